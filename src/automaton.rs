@@ -0,0 +1,214 @@
+use std::collections::{HashMap as Map, HashSet as Set};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::grid::{Grid, Neighborhood, Pos};
+use crate::mesh::Mesh;
+
+/// Cellular automaton: applies a local rule to every cell at once, the rule
+/// gets current cell value and values of its neighbors
+pub struct Automaton<F> {
+    deltas: Vec<Pos>,
+    rule: F,
+}
+
+impl<F> Automaton<F> {
+    /// Create a new automaton over a standard neighborhood
+    pub fn new(hood: Neighborhood, rule: F) -> Self {
+        Self::with_deltas(hood.deltas(), rule)
+    }
+
+    /// Create a new automaton over a custom neighborhood given as offsets
+    pub fn with_deltas(deltas: Vec<Pos>, rule: F) -> Self {
+        Self { deltas, rule }
+    }
+
+    /// Compute next generation of the grid into a buffer of the same size
+    /// (neighbors outside of the grid are not passed to the rule)
+    pub fn step_grid<T>(&self, src: &Grid<T>, dst: &mut Grid<T>)
+    where
+        T: Clone + Debug + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        assert_eq!(src.size(), dst.size(), "buffer size mismatch");
+        let mut adj = Vec::with_capacity(self.deltas.len());
+        for (row, col, val) in src.iter() {
+            adj.clear();
+            adj.extend(
+                self.deltas
+                    .iter()
+                    .filter_map(|d| src.offset(&(row, col), *d))
+                    .map(|cell| src.get(&cell).unwrap()),
+            );
+            dst.set(&(row, col), (self.rule)(val, &adj));
+        }
+    }
+
+    /// Run the automaton on a grid for `n` generations (double-buffered)
+    pub fn run_grid<T>(&self, mut grid: Grid<T>, n: usize) -> Grid<T>
+    where
+        T: Clone + Debug + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        let mut buf = grid.clone();
+        for _ in 0..n {
+            self.step_grid(&grid, &mut buf);
+            std::mem::swap(&mut grid, &mut buf);
+        }
+        grid
+    }
+
+    /// Get grid after `n` generations, skipping ahead once a repeated state
+    /// is detected
+    pub fn nth_grid<T>(&self, grid: Grid<T>, n: usize) -> Grid<T>
+    where
+        T: Clone + Debug + Eq + Hash + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        let mut buf = grid.clone();
+        jump(grid, n, Grid::clone, |grid| {
+            self.step_grid(&grid, &mut buf);
+            std::mem::replace(&mut buf, grid)
+        })
+    }
+
+    /// Compute next generation of the mesh; the rule must map a default
+    /// cell surrounded by default cells to the default value
+    pub fn step_mesh<T>(&self, mesh: &Mesh<T>) -> Mesh<T>
+    where
+        T: Clone + Debug + PartialEq + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        let candidates = mesh
+            .iter()
+            .flat_map(|(pos, _)| {
+                std::iter::once(*pos).chain(
+                    self.deltas.iter().map(|d| (pos.0 - d.0, pos.1 - d.1)),
+                )
+            })
+            .collect::<Set<_>>();
+        let mut ret = Mesh::new(mesh.default_value().clone());
+        let mut adj = Vec::with_capacity(self.deltas.len());
+        for pos in candidates {
+            adj.clear();
+            adj.extend(
+                self.deltas
+                    .iter()
+                    .map(|d| mesh.get(&(pos.0 + d.0, pos.1 + d.1))),
+            );
+            ret.set(&pos, (self.rule)(mesh.get(&pos), &adj));
+        }
+        ret
+    }
+
+    /// Run the automaton on a mesh for `n` generations
+    pub fn run_mesh<T>(&self, mut mesh: Mesh<T>, n: usize) -> Mesh<T>
+    where
+        T: Clone + Debug + PartialEq + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        for _ in 0..n {
+            mesh = self.step_mesh(&mesh);
+        }
+        mesh
+    }
+
+    /// Get mesh after `n` generations, skipping ahead once a repeated state
+    /// is detected
+    pub fn nth_mesh<T>(&self, mesh: Mesh<T>, n: usize) -> Mesh<T>
+    where
+        T: Clone + Debug + Ord + Hash + 'static,
+        F: Fn(&T, &[&T]) -> T,
+    {
+        let key = |mesh: &Mesh<T>| {
+            let mut cells = mesh
+                .iter()
+                .map(|(pos, val)| (*pos, val.clone()))
+                .collect::<Vec<_>>();
+            cells.sort();
+            cells
+        };
+        jump(mesh, n, key, |mesh| self.step_mesh(&mesh))
+    }
+}
+
+/// Get state after `n` steps: once a state (identified by a key) repeats,
+/// the remaining steps are skipped by the length of the cycle
+pub fn jump<S, K: Eq + Hash>(
+    init: S,
+    n: usize,
+    key: impl Fn(&S) -> K,
+    mut step: impl FnMut(S) -> S,
+) -> S {
+    let mut seen: Map<K, usize> = Map::new();
+    let mut state = init;
+    let mut i = 0;
+    while i < n {
+        if let Some(prev) = seen.insert(key(&state), i) {
+            let period = i - prev;
+            let rest = (n - i) % period;
+            for _ in 0..rest {
+                state = step(state);
+            }
+            return state;
+        }
+        state = step(state);
+        i += 1;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(cell: &char, adj: &[&char]) -> char {
+        let alive = adj.iter().filter(|c| ***c == '#').count();
+        match (cell, alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let blinker = Grid::raw(vec![
+            ".....".to_owned(),
+            "..#..".to_owned(),
+            "..#..".to_owned(),
+            "..#..".to_owned(),
+            ".....".to_owned(),
+        ]);
+        let life = Automaton::new(Neighborhood::Eight, life);
+        let next = life.run_grid(blinker.clone(), 1);
+        assert_eq!(next.dump(|_, c| c), ".....\n.....\n.###.\n.....\n.....");
+        assert_eq!(life.run_grid(blinker.clone(), 2), blinker);
+        assert_eq!(life.nth_grid(blinker.clone(), 1_000_000_001), next);
+    }
+
+    #[test]
+    fn test_mesh() {
+        let glider = Grid::raw(vec![
+            ".#.".to_owned(),
+            "..#".to_owned(),
+            "###".to_owned(),
+        ]);
+        let life = Automaton::new(Neighborhood::Eight, life);
+        let mesh = life.run_mesh(Mesh::from_grid(&glider, '.'), 4);
+        assert_eq!(mesh.len(), 5);
+        assert_eq!(mesh.bounds(), Some(((1, 1), (3, 3))));
+        assert_eq!(mesh.dump(|_, c| *c), glider.dump(|_, c| c));
+
+        let mesh = life.nth_mesh(Mesh::from_grid(&glider, '.'), 400);
+        assert_eq!(mesh.bounds(), Some(((100, 100), (102, 102))));
+    }
+
+    #[test]
+    fn test_jump() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+        let step = |x: usize| if x == 4 { 2 } else { x + 1 };
+        assert_eq!(jump(0, 3, |x| *x, step), 3);
+        assert_eq!(jump(0, 5, |x| *x, step), 2);
+        assert_eq!(jump(0, 1_000_000, |x| *x, step), 2 + (1_000_000 - 2) % 3);
+    }
+}
@@ -0,0 +1,120 @@
+use std::process::exit;
+use std::time::Instant;
+
+use advent_of_code_2023::days;
+use advent_of_code_2023::solution::{Part, Runner};
+
+const USAGE: &str = "usage: aoc run <day|all> [--part 1|2] [--input PATH]";
+
+// aoc run 7
+// aoc run 5 --part 2
+// aoc run 5 --input txt/day05.txt
+// aoc run all
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (days, part, input) = parse(&args).unwrap_or_else(|e| {
+        eprintln!("{e}\n{USAGE}");
+        exit(2);
+    });
+    let all = days.len() > 1;
+    for (day, runner) in days {
+        let path = input.clone().unwrap_or(format!("txt/day{day:02}.txt"));
+        let lines = match std::fs::read_to_string(&path) {
+            Ok(text) => text.lines().map(|line| line.to_owned()).collect(),
+            Err(e) => {
+                eprintln!("day{day:02}: failed to read '{path}': {e}");
+                exit(1);
+            }
+        };
+        if all {
+            println!("--- day{day:02}");
+        }
+        let now = Instant::now();
+        for answer in runner(lines, part) {
+            println!("{answer}");
+        }
+        if all {
+            println!("time: {:.3}s", now.elapsed().as_secs_f64());
+        }
+    }
+}
+
+type Args = (Vec<(u32, Runner)>, Part, Option<String>);
+
+fn parse(args: &[String]) -> Result<Args, String> {
+    let mut it = args.iter();
+    match it.next().map(String::as_str) {
+        Some("run") => (),
+        Some(cmd) => return Err(format!("unknown command: '{cmd}'")),
+        None => return Err("missing command".to_owned()),
+    }
+    let days = match it.next().map(String::as_str) {
+        Some("all") => days::ALL.to_vec(),
+        Some(day) => {
+            let day = day
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day: '{day}'"))?;
+            let runner = days::get(day)
+                .ok_or_else(|| format!("no solution for day {day}"))?;
+            vec![(day, runner)]
+        }
+        None => return Err("missing day".to_owned()),
+    };
+    let mut part = Part::Both;
+    let mut input = None;
+    while let Some(arg) = it.next() {
+        match (arg.as_str(), it.next().map(String::as_str)) {
+            ("--part", Some("1")) => part = Part::One,
+            ("--part", Some("2")) => part = Part::Two,
+            ("--part", value) => {
+                let value = value.unwrap_or_default();
+                return Err(format!("invalid part: '{value}'"));
+            }
+            ("--input", Some(path)) if days.len() == 1 => {
+                input = Some(path.to_owned());
+            }
+            ("--input", _) => {
+                return Err("--input requires a path and a single day".into());
+            }
+            (arg, _) => return Err(format!("unknown argument: '{arg}'")),
+        }
+    }
+    Ok((days, part, input))
+}
+
+#[cfg(test)]
+mod aoc {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let (days, part, input) = parse(&args("run 7")).unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].0, 7);
+        assert_eq!(part, Part::Both);
+        assert_eq!(input, None);
+
+        let (days, part, _) = parse(&args("run all --part 2")).unwrap();
+        assert_eq!(days.len(), days::ALL.len());
+        assert_eq!(part, Part::Two);
+
+        let (_, part, input) =
+            parse(&args("run 5 --input x.txt --part 1")).unwrap();
+        assert_eq!(part, Part::One);
+        assert_eq!(input.as_deref(), Some("x.txt"));
+
+        assert!(parse(&args("")).is_err());
+        assert!(parse(&args("go 7")).is_err());
+        assert!(parse(&args("run")).is_err());
+        assert!(parse(&args("run 42")).is_err());
+        assert!(parse(&args("run x")).is_err());
+        assert!(parse(&args("run 5 --part 3")).is_err());
+        assert!(parse(&args("run all --input x.txt")).is_err());
+        assert!(parse(&args("run 5 --verbose")).is_err());
+    }
+}
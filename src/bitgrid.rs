@@ -0,0 +1,313 @@
+use std::fmt::Debug;
+
+use crate::grid::{Cell, Dir, Grid};
+
+/// Dense grid of bits (one bit per cell), possibly with several planes
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BitGrid {
+    rows: usize,
+    cols: usize,
+    planes: usize,
+    words: usize,
+    data: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Create a new empty grid with a single plane
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::with_planes(rows, cols, 1)
+    }
+
+    /// Create a new empty grid with a given number of planes
+    pub fn with_planes(rows: usize, cols: usize, planes: usize) -> Self {
+        let words = cols.div_ceil(64);
+        Self {
+            rows,
+            cols,
+            planes,
+            words,
+            data: vec![0; planes * rows * words],
+        }
+    }
+
+    /// Create a new single-plane grid with bits set where predicate holds
+    pub fn from_grid<T: Clone + Debug + 'static>(
+        grid: &Grid<T>,
+        f: impl Fn(&T) -> bool,
+    ) -> Self {
+        let (rows, cols) = grid.size();
+        let mut ret = Self::new(rows, cols);
+        for (row, col, val) in grid.iter() {
+            if f(val) {
+                ret.set(&(row, col), true);
+            }
+        }
+        ret
+    }
+
+    /// Get grid size (rows, cols)
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Get number of planes
+    pub fn planes(&self) -> usize {
+        self.planes
+    }
+
+    fn index(&self, plane: usize, pos: &Cell) -> Option<(usize, u64)> {
+        let (row, col) = *pos;
+        if plane >= self.planes || row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let idx = (plane * self.rows + row) * self.words + col / 64;
+        Some((idx, 1 << (col % 64)))
+    }
+
+    /// Get bit at a given position (first plane)
+    pub fn get(&self, pos: &Cell) -> bool {
+        self.get_in(0, pos)
+    }
+
+    /// Get bit at a given position of a given plane
+    pub fn get_in(&self, plane: usize, pos: &Cell) -> bool {
+        self.index(plane, pos)
+            .map(|(idx, bit)| self.data[idx] & bit != 0)
+            .unwrap_or_default()
+    }
+
+    /// Set bit at a given position (first plane)
+    pub fn set(&mut self, pos: &Cell, val: bool) {
+        self.set_in(0, pos, val);
+    }
+
+    /// Set bit at a given position of a given plane
+    pub fn set_in(&mut self, plane: usize, pos: &Cell, val: bool) {
+        let (idx, bit) = self.index(plane, pos).expect("out of bounds");
+        if val {
+            self.data[idx] |= bit;
+        } else {
+            self.data[idx] &= !bit;
+        }
+    }
+
+    /// Set bit at a given position of a given plane, returns true if the
+    /// bit was not set before
+    pub fn insert(&mut self, plane: usize, pos: &Cell) -> bool {
+        let (idx, bit) = self.index(plane, pos).expect("out of bounds");
+        let ret = self.data[idx] & bit == 0;
+        self.data[idx] |= bit;
+        ret
+    }
+
+    /// Count set bits in all planes
+    pub fn count(&self) -> usize {
+        self.data.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Count set bits in a given plane
+    pub fn count_in(&self, plane: usize) -> usize {
+        self.plane(plane)
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over positions of set bits in a given plane, by rows
+    pub fn iter_in(&self, plane: usize) -> impl Iterator<Item = Cell> + '_ {
+        self.plane(plane)
+            .chunks(self.words.max(1))
+            .enumerate()
+            .flat_map(|(row, words)| {
+                words.iter().enumerate().flat_map(move |(i, word)| {
+                    (0..64)
+                        .filter(move |bit| word & (1 << bit) != 0)
+                        .map(move |bit| (row, i * 64 + bit))
+                })
+            })
+    }
+
+    /// Get single-plane grid with bits set in any of the planes
+    pub fn flatten(&self) -> BitGrid {
+        let mut ret = Self::new(self.rows, self.cols);
+        for plane in 0..self.planes {
+            for (dst, src) in ret.data.iter_mut().zip(self.plane(plane)) {
+                *dst |= src;
+            }
+        }
+        ret
+    }
+
+    /// Bitwise AND with another grid of the same shape
+    pub fn and(&self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a & b)
+    }
+
+    /// Bitwise OR with another grid of the same shape
+    pub fn or(&self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a | b)
+    }
+
+    /// Bitwise XOR with another grid of the same shape
+    pub fn xor(&self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a ^ b)
+    }
+
+    /// Bitwise AND NOT (set difference) with another grid of the same shape
+    pub fn and_not(&self, other: &BitGrid) -> BitGrid {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    /// Bitwise NOT (within grid bounds)
+    pub fn not(&self) -> BitGrid {
+        let mut ret = self.clone();
+        ret.data.iter_mut().for_each(|w| *w = !*w);
+        ret.mask();
+        ret
+    }
+
+    /// Move all bits one cell in a given direction, bits moved over the
+    /// edge are dropped
+    pub fn shift(&self, dir: Dir) -> BitGrid {
+        let mut ret = Self::with_planes(self.rows, self.cols, self.planes);
+        let w = self.words;
+        for plane in 0..self.planes {
+            for row in 0..self.rows {
+                let src = (plane * self.rows + row) * w;
+                match dir {
+                    Dir::North if row + 1 < self.rows => {
+                        ret.data[src..src + w]
+                            .copy_from_slice(&self.data[src + w..src + 2 * w]);
+                    }
+                    Dir::South if row > 0 => {
+                        ret.data[src..src + w]
+                            .copy_from_slice(&self.data[src - w..src]);
+                    }
+                    Dir::East => {
+                        let mut carry = 0;
+                        for i in 0..w {
+                            let word = self.data[src + i];
+                            ret.data[src + i] = (word << 1) | carry;
+                            carry = word >> 63;
+                        }
+                    }
+                    Dir::West => {
+                        let mut carry = 0;
+                        for i in (0..w).rev() {
+                            let word = self.data[src + i];
+                            ret.data[src + i] = (word >> 1) | (carry << 63);
+                            carry = word & 1;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        ret.mask();
+        ret
+    }
+
+    /// Get string representation of a given plane
+    pub fn dump(&self, plane: usize) -> String {
+        (0..self.rows)
+            .map(|row| {
+                (0..self.cols)
+                    .map(|col| {
+                        if self.get_in(plane, &(row, col)) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn plane(&self, plane: usize) -> &[u64] {
+        let len = self.rows * self.words;
+        &self.data[plane * len..(plane + 1) * len]
+    }
+
+    fn zip(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(
+            (self.rows, self.cols, self.planes),
+            (other.rows, other.cols, other.planes),
+            "bit grid shape mismatch"
+        );
+        let mut ret = self.clone();
+        for (a, b) in ret.data.iter_mut().zip(other.data.iter()) {
+            *a = f(*a, *b);
+        }
+        ret
+    }
+
+    // Clear bits beyond the last column of each row.
+    fn mask(&mut self) {
+        let tail = self.cols % 64;
+        if tail == 0 || self.words == 0 {
+            return;
+        }
+        let mask = (1u64 << tail) - 1;
+        for row in self.data.chunks_mut(self.words) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits() {
+        let grid = Grid::raw(vec!["#..#".to_owned(), ".##.".to_owned()]);
+        let bits = BitGrid::from_grid(&grid, |c| c == &'#');
+        assert_eq!(bits.count(), 4);
+        assert_eq!(bits.dump(0), "#..#\n.##.");
+        assert_eq!(
+            bits.iter_in(0).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 3), (1, 1), (1, 2)]
+        );
+        assert_eq!(bits.not().dump(0), ".##.\n#..#");
+        assert_eq!(bits.and(&bits.not()).count(), 0);
+        assert_eq!(bits.or(&bits.not()).count(), 8);
+        assert_eq!(bits.xor(&bits).count(), 0);
+
+        assert_eq!(bits.shift(Dir::East).dump(0), ".#..\n..##");
+        assert_eq!(bits.shift(Dir::West).dump(0), "..#.\n##..");
+        assert_eq!(bits.shift(Dir::North).dump(0), ".##.\n....");
+        assert_eq!(bits.shift(Dir::South).dump(0), "....\n#..#");
+    }
+
+    #[test]
+    fn test_wide() {
+        let mut bits = BitGrid::new(2, 130);
+        bits.set(&(0, 63), true);
+        bits.set(&(1, 129), true);
+        let east = bits.shift(Dir::East);
+        assert!(east.get(&(0, 64)));
+        assert_eq!(east.count(), 1);
+        let west = bits.shift(Dir::West);
+        assert!(west.get(&(0, 62)));
+        assert!(west.get(&(1, 128)));
+        assert_eq!(bits.not().count(), 2 * 130 - 2);
+    }
+
+    #[test]
+    fn test_planes() {
+        let mut bits = BitGrid::with_planes(3, 3, 4);
+        assert!(bits.insert(0, &(1, 1)));
+        assert!(!bits.insert(0, &(1, 1)));
+        assert!(bits.insert(2, &(1, 1)));
+        assert!(bits.insert(3, &(2, 0)));
+        assert_eq!(bits.count(), 3);
+        assert_eq!(bits.count_in(2), 1);
+        assert!(!bits.get_in(1, &(1, 1)));
+        assert_eq!(bits.flatten().count(), 2);
+
+        let grown = bits.or(&bits.shift(Dir::North));
+        assert_eq!(grown.count_in(3), 2);
+    }
+}
@@ -0,0 +1,180 @@
+use std::convert::Infallible;
+use std::fmt::{self, Debug, Display};
+
+use crate::grid::{Grid, GridError};
+
+/// Grid parsed from a block of input together with its header
+pub type Block<H, T> = (H, Grid<T>);
+
+/// Error produced while parsing blocks of input, with 1-based line number
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BlockError<H, E> {
+    /// Header extractor rejected the first line of a block
+    Header { line: usize, err: H },
+    /// Grid lines of a block are invalid
+    Grid { line: usize, err: GridError<E> },
+}
+
+impl<H: Display, E: Display> Display for BlockError<H, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockError::Header { line, err } => {
+                write!(f, "line {line}: invalid header: {err}")
+            }
+            BlockError::Grid { line, err } => write!(f, "line {line}: {err}"),
+        }
+    }
+}
+
+impl<H: Debug + Display, E: Debug + Display> std::error::Error
+    for BlockError<H, E>
+{
+}
+
+/// Split lines into non-empty blocks separated by blank lines; each block
+/// comes with the index of its first line
+pub fn split(lines: &[String]) -> Vec<(usize, &[String])> {
+    let mut ret = Vec::new();
+    let mut start = 0;
+    for (idx, line) in lines.iter().chain([&String::new()]).enumerate() {
+        if line.trim().is_empty() {
+            if idx > start {
+                ret.push((start, &lines[start..idx]));
+            }
+            start = idx + 1;
+        }
+    }
+    ret
+}
+
+/// Parse blocks separated by blank lines into grids
+pub fn grids<T: Clone + Debug + 'static, E>(
+    lines: &[String],
+    f: impl Fn(char) -> Result<T, E>,
+) -> Result<Vec<Grid<T>>, BlockError<Infallible, E>> {
+    split(lines)
+        .into_iter()
+        .map(|(start, block)| grid(start, block, &f))
+        .collect()
+}
+
+/// Parse blocks separated by blank lines into grids, where the first line
+/// of every block is a header
+pub fn with_headers<H, T: Clone + Debug + 'static, EH, E>(
+    lines: &[String],
+    header: impl Fn(&str) -> Result<H, EH>,
+    f: impl Fn(char) -> Result<T, E>,
+) -> Result<Vec<Block<H, T>>, BlockError<EH, E>> {
+    split(lines)
+        .into_iter()
+        .map(|(start, block)| {
+            let head = header(&block[0]).map_err(|err| BlockError::Header {
+                line: start + 1,
+                err,
+            })?;
+            let grid =
+                grid(start + 1, &block[1..], &f).map_err(|e| match e {
+                    BlockError::Grid { line, err } => {
+                        BlockError::Grid { line, err }
+                    }
+                    BlockError::Header { err, .. } => match err {},
+                })?;
+            Ok((head, grid))
+        })
+        .collect()
+}
+
+fn grid<T: Clone + Debug + 'static, E>(
+    start: usize,
+    block: &[String],
+    f: impl Fn(char) -> Result<T, E>,
+) -> Result<Grid<T>, BlockError<Infallible, E>> {
+    Grid::try_new(block.to_vec(), f).map_err(|err| {
+        let row = match &err {
+            GridError::Empty => 0,
+            GridError::Ragged { row, .. } | GridError::Cell { row, .. } => *row,
+        };
+        BlockError::Grid {
+            line: start + row + 1,
+            err,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines().map(|line| line.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let lines = lines("\nab\ncd\n\n\nef\n");
+        let blocks = split(&lines);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0], (1, &lines[1..3]));
+        assert_eq!(blocks[1], (5, &lines[5..6]));
+        assert!(split(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_grids() {
+        let lines = lines("#.\n.#\n\n..\n##\n..");
+        let grids = grids(&lines, |c| Ok::<_, Infallible>(c == '#')).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[1].size(), (3, 2));
+        assert_eq!(grids[1].get(&(1, 0)), Some(&true));
+
+        let err = super::grids(&lines, |c| match c {
+            '#' => Ok(true),
+            '.' => Err("no dots in second block"),
+            _ => unreachable!(),
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: invalid cell '.' at (0, 1): no dots in second block"
+        );
+    }
+
+    #[test]
+    fn test_headers() {
+        let lines = lines("Tile 2311:\n#.\n.#\n\nTile 1951:\n..\n.");
+        let parse_header = |line: &str| {
+            line.strip_prefix("Tile ")
+                .and_then(|s| s.strip_suffix(':'))
+                .ok_or("missing tile id")?
+                .parse::<u32>()
+                .map_err(|_| "invalid tile id")
+        };
+        let cell = Ok::<char, Infallible>;
+
+        let err = with_headers(&lines, parse_header, cell).unwrap_err();
+        assert_eq!(
+            err,
+            BlockError::Grid {
+                line: 7,
+                err: GridError::Ragged {
+                    row: 1,
+                    expected: 2,
+                    actual: 1,
+                },
+            }
+        );
+
+        let mut lines = lines;
+        lines[6] = "##".to_owned();
+        let blocks = with_headers(&lines, parse_header, cell).unwrap();
+        assert_eq!(
+            blocks.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![2311, 1951]
+        );
+        assert_eq!(blocks[1].1.dump(|_, c| c), "..\n##");
+
+        lines[4] = "Tile X:".to_owned();
+        let err = with_headers(&lines, parse_header, cell).unwrap_err();
+        assert_eq!(err.to_string(), "line 5: invalid header: invalid tile id");
+    }
+}
@@ -0,0 +1,192 @@
+use crate::grid::Cell;
+
+/// Mapping of a point set to coordinates where every empty row and column
+/// (one containing no points) is stretched into `scale` rows or columns
+#[derive(Clone, Debug)]
+pub struct Expansion {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    scale: usize,
+}
+
+impl Expansion {
+    /// Create a new expansion of given points with given scale factor
+    pub fn new(points: &[Cell], scale: usize) -> Self {
+        let rows = prefix(points.iter().map(|(row, _)| *row), scale);
+        let cols = prefix(points.iter().map(|(_, col)| *col), scale);
+        Self { rows, cols, scale }
+    }
+
+    /// Get expanded row index
+    pub fn row(&self, row: usize) -> usize {
+        expand(&self.rows, row, self.scale)
+    }
+
+    /// Get expanded column index
+    pub fn col(&self, col: usize) -> usize {
+        expand(&self.cols, col, self.scale)
+    }
+
+    /// Get expanded position of a cell
+    pub fn map(&self, cell: &Cell) -> Cell {
+        (self.row(cell.0), self.col(cell.1))
+    }
+
+    /// Get Manhattan distance between two cells after expansion
+    pub fn distance(&self, a: &Cell, b: &Cell) -> usize {
+        let (a, b) = (self.map(a), self.map(b));
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+    }
+}
+
+// Expanded index of every original index up to the largest occupied one.
+fn prefix(occupied: impl Iterator<Item = usize>, scale: usize) -> Vec<usize> {
+    let mut used = Vec::new();
+    for idx in occupied {
+        if idx >= used.len() {
+            used.resize(idx + 1, false);
+        }
+        used[idx] = true;
+    }
+    let mut ret = Vec::with_capacity(used.len());
+    let mut acc = 0;
+    for used in used {
+        ret.push(acc);
+        acc += if used { 1 } else { scale };
+    }
+    ret
+}
+
+fn expand(prefix: &[usize], idx: usize, scale: usize) -> usize {
+    match prefix.last() {
+        _ if idx < prefix.len() => prefix[idx],
+        // Everything past the last occupied index is empty.
+        Some(last) => last + 1 + (idx - prefix.len()) * scale,
+        None => idx * scale,
+    }
+}
+
+/// Sum of Manhattan distances over all unordered pairs of cells
+pub fn pairwise_manhattan(cells: &[Cell]) -> usize {
+    fn sum(mut xs: Vec<usize>) -> usize {
+        xs.sort_unstable();
+        let mut acc = 0;
+        let mut ret = 0;
+        for (idx, x) in xs.into_iter().enumerate() {
+            ret += x * idx - acc;
+            acc += x;
+        }
+        ret
+    }
+    sum(cells.iter().map(|(row, _)| *row).collect())
+        + sum(cells.iter().map(|(_, col)| *col).collect())
+}
+
+/// Coordinate compression: maps sorted distinct values to dense indices
+#[derive(Clone, Debug)]
+pub struct Compressed<T: Ord> {
+    values: Vec<T>,
+}
+
+impl<T: Ord> Compressed<T> {
+    /// Create a new compression of given values
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    /// Get number of distinct values
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if there are no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get dense index of a value (if present)
+    pub fn index(&self, value: &T) -> Option<usize> {
+        self.values.binary_search(value).ok()
+    }
+
+    /// Get value at a given dense index
+    pub fn value(&self, idx: usize) -> Option<&T> {
+        self.values.get(idx)
+    }
+
+    /// Get all distinct values in order
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn stars() -> Vec<Cell> {
+        let lines = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+        Grid::raw(lines.lines().map(|s| s.to_owned()).collect())
+            .find(|c| c == &'#')
+    }
+
+    #[test]
+    fn test_expansion() {
+        let stars = stars();
+        let exp = Expansion::new(&stars, 2);
+        assert_eq!(exp.map(&(0, 3)), (0, 4));
+        assert_eq!(exp.map(&(9, 4)), (11, 5));
+        assert_eq!(exp.row(10), 12);
+        assert_eq!(exp.distance(&(5, 1), &(9, 4)), 9);
+
+        let sum = |scale| {
+            let exp = Expansion::new(&stars, scale);
+            pairwise_manhattan(
+                &stars.iter().map(|s| exp.map(s)).collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(sum(2), 374);
+        assert_eq!(sum(10), 1030);
+        assert_eq!(sum(100), 8410);
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let cells: Vec<Cell> = vec![(0, 0), (3, 1), (1, 5), (2, 2)];
+        let naive = cells
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                cells[i + 1..]
+                    .iter()
+                    .map(move |b| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            })
+            .sum::<usize>();
+        assert_eq!(pairwise_manhattan(&cells), naive);
+        assert_eq!(pairwise_manhattan(&[]), 0);
+    }
+
+    #[test]
+    fn test_compressed() {
+        let xs = Compressed::new([1_000_000_000i64, -5, 42, 42, 7]);
+        assert_eq!(xs.len(), 4);
+        assert_eq!(xs.index(&-5), Some(0));
+        assert_eq!(xs.index(&42), Some(2));
+        assert_eq!(xs.index(&43), None);
+        assert_eq!(xs.value(3), Some(&1_000_000_000));
+        assert_eq!(xs.values(), &[-5, 7, 42, 1_000_000_000]);
+    }
+}
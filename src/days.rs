@@ -0,0 +1,80 @@
+use crate::solution::{run, Runner};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+/// All finished solutions by day number (run by `aoc run all`)
+pub const ALL: [(u32, Runner); 17] = [
+    (1, run::<day01::Day01>),
+    (2, run::<day02::Day02>),
+    (3, run::<day03::Day03>),
+    (4, run::<day04::Day04>),
+    (5, run::<day05::Day05>),
+    (6, run::<day06::Day06>),
+    (7, run::<day07::Day07>),
+    (8, run::<day08::Day08>),
+    (9, run::<day09::Day09>),
+    (10, run::<day10::Day10>),
+    (11, run::<day11::Day11>),
+    (12, run::<day12::Day12>),
+    (13, run::<day13::Day13>),
+    (14, run::<day14::Day14>),
+    (15, run::<day15::Day15>),
+    (16, run::<day16::Day16>),
+    (18, run::<day18::Day18>),
+];
+
+/// Unfinished solutions, runnable only one at a time: day17 answers do not
+/// match the recorded ones (part 2 finds no path and overflows in debug
+/// builds), day19 part 2 is wrong and day20 part 1 never terminates
+pub const UNFINISHED: [(u32, Runner); 3] = [
+    (17, run::<day17::Day17>),
+    (19, run::<day19::Day19>),
+    (20, run::<day20::Day20>),
+];
+
+/// Get solution for a given day
+pub fn get(day: u32) -> Option<Runner> {
+    ALL.iter()
+        .chain(UNFINISHED.iter())
+        .find(|(d, _)| *d == day)
+        .map(|(_, runner)| *runner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_registry() {
+        let days = ALL.iter().map(|(day, _)| *day).collect::<Vec<_>>();
+        assert_eq!(days, (1..=18).filter(|d| *d != 17).collect::<Vec<_>>());
+        assert!(get(17).is_some());
+        assert!(get(19).is_some());
+        assert!(get(20).is_some());
+        assert!(get(0).is_none());
+        assert!(get(21).is_none());
+
+        let lines = vec!["1abc2".to_owned(), "pqr3stu8vwx".to_owned()];
+        assert_eq!(get(1).unwrap()(lines, Part::One), vec!["50"]);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::solution::Solution;
+use crate::token::{self, Span};
+use crate::*;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = (Vec<Span>, Grid<char>);
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        let grid = Grid::raw(lines);
+        (extract_numbers(&grid), grid)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let (nums, grid) = input;
+        part1(nums, grid).to_string() // 533784
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        let (nums, grid) = input;
+        Some(part2(nums, grid).to_string()) // 78826761
+    }
+}
+
+fn part1(nums: &[Span], grid: &Grid<char>) -> usize {
+    fn is_symbol(c: &char) -> bool {
+        c != &'.' && !c.is_ascii_digit()
+    }
+    nums.iter()
+        .filter(|number| {
+            number
+                .neighbors(grid)
+                .iter()
+                .filter_map(|pos| grid.get(pos))
+                .any(is_symbol)
+        })
+        .filter_map(|number| number.parse::<usize>())
+        .sum::<usize>()
+}
+
+fn part2(nums: &[Span], grid: &Grid<char>) -> usize {
+    let mut ret = 0;
+
+    let gears = grid.find(|c| c == &'*');
+    for gear in gears {
+        let numbers = token::touching(nums, &gear)
+            .filter_map(|n| n.parse::<usize>())
+            .collect::<Vec<_>>();
+
+        if numbers.len() == 2 {
+            let ratio = numbers[0] * numbers[1];
+            ret += ratio;
+        }
+    }
+
+    ret
+}
+
+fn extract_numbers(grid: &Grid<char>) -> Vec<Span> {
+    grid.tokens(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_numbers(line: &str) -> Vec<(usize, usize, usize)> {
+        extract_numbers(&Grid::raw(vec![line.to_owned()]))
+            .into_iter()
+            .filter_map(|span| {
+                Some((span.cols.start, span.len(), span.parse()?))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_numbers() {
+        for (line, expected) in [
+            //0123456789
+            ("467..114..", vec![(0, 3, 467), (5, 3, 114)]),
+            ("467#%114&*", vec![(0, 3, 467), (5, 3, 114)]),
+            ("...*......", vec![]),
+            ("..35..633.", vec![(2, 2, 35), (6, 3, 633)]),
+            (".#35**633#", vec![(2, 2, 35), (6, 3, 633)]),
+            (".........1", vec![(9, 1, 1)]),
+            (".......123", vec![(7, 3, 123)]),
+            ("...#1#....", vec![(4, 1, 1)]),
+            ("...#1#2...", vec![(4, 1, 1), (6, 1, 2)]),
+            ("...#1#2#..", vec![(4, 1, 1), (6, 1, 2)]),
+            ("....1.2...", vec![(4, 1, 1), (6, 1, 2)]),
+        ] {
+            assert_eq!(find_numbers(line), expected);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        lines.into_iter().map(|line| parse(&line)).collect()
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string() // 1772145754
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input).to_string()) // 867
+    }
+}
+
+fn part2(input: &[Vec<isize>]) -> isize {
+    input
+        .iter()
+        .map(|xs| xs.clone().into_iter().rev().collect::<Vec<_>>())
+        .map(|xs| extrapolate(&xs))
+        .sum::<isize>()
+}
+
+fn part1(input: &[Vec<isize>]) -> isize {
+    input.iter().map(|xs| extrapolate(xs)).sum::<isize>()
+}
+
+fn extrapolate(seq: &[isize]) -> isize {
+    let mut end = vec![seq[seq.len() - 1]];
+    let mut acc = seq.to_vec();
+    while acc.iter().any(|x| x != &0) {
+        acc = acc
+            .iter()
+            .zip(acc.iter().skip(1))
+            .map(|(a, b)| *b - *a)
+            .collect();
+        end.push(acc[acc.len() - 1]);
+    }
+    end.into_iter().sum()
+}
+
+fn parse(line: &str) -> Vec<isize> {
+    line.split_whitespace()
+        .map(|number| number.parse().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        let seq = &[10, 13, 16, 21, 30, 45];
+        assert_eq!(extrapolate(seq), 68);
+        let rev = (*seq).into_iter().rev().collect::<Vec<_>>();
+        assert_eq!(extrapolate(&rev), 5);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::solution::Solution;
+use crate::*;
+use grid::{Dir8, Grid, Neighborhood};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Grid<char>, (usize, usize));
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        let grid = Grid::raw(lines);
+        let start = {
+            let found = grid.find(|c| *c == 'S');
+            assert_eq!(
+                found.len(),
+                1,
+                "grid must contain one starting point S"
+            );
+            found[0]
+        };
+        (grid, start)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let (grid, start) = input;
+        part1(grid, start).to_string() // 6890
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        let (grid, start) = input;
+        Some(part2(grid, start).to_string()) // 453
+    }
+}
+
+fn part2(grid: &Grid<char>, start: &(usize, usize)) -> usize {
+    let path = trace(grid, start)
+        .into_iter()
+        .map(|(row, col)| (row as isize, col as isize))
+        .collect::<Vec<_>>();
+    grid::interior(&path).len()
+}
+
+fn trace(grid: &Grid<char>, start: &(usize, usize)) -> Vec<(usize, usize)> {
+    let mut path = vec![*start];
+    let mut prev = *start;
+    let mut node = adj(grid, start)[0];
+    while node != *start {
+        path.push(node);
+        let next = adj(grid, &node)
+            .into_iter()
+            .find(|next| *next != prev)
+            .expect("loop must not have dead ends");
+        prev = node;
+        node = next;
+    }
+    path
+}
+
+fn part1(grid: &Grid<char>, start: &(usize, usize)) -> usize {
+    let cycle = bfs(grid, start);
+    cycle.len() / 2
+}
+
+/*
+| is a vertical pipe connecting north and south.
+- is a horizontal pipe connecting east and west.
+L is a 90-degree bend connecting north and east.
+J is a 90-degree bend connecting north and west.
+7 is a 90-degree bend connecting south and west.
+F is a 90-degree bend connecting south and east.
+*/
+fn can_move(grid: &Grid<char>, src: &(usize, usize), dir: Dir8) -> bool {
+    let chr = *grid.get(src).unwrap();
+    match (chr, dir) {
+        ('|', Dir8::North | Dir8::South) => true,
+        ('-', Dir8::West | Dir8::East) => true,
+        ('L', Dir8::East | Dir8::North) => true,
+        ('J', Dir8::West | Dir8::North) => true,
+        ('7', Dir8::West | Dir8::South) => true,
+        ('F', Dir8::East | Dir8::South) => true,
+        ('S', _) => grid
+            .offset(src, dir.delta())
+            .map(|dst| can_move(grid, &dst, dir.inv()))
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+fn adj(grid: &Grid<char>, at: &(usize, usize)) -> Vec<(usize, usize)> {
+    grid.neighbors(at, Neighborhood::Four)
+        .into_iter()
+        .filter(|(_, dir)| can_move(grid, at, *dir))
+        .map(|(to, _)| to)
+        .collect()
+}
+
+fn bfs(grid: &Grid<char>, start: &(usize, usize)) -> HashSet<(usize, usize)> {
+    let mut prev: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    queue.push_back(*start);
+    while !queue.is_empty() {
+        let node = queue.pop_front().unwrap();
+        seen.insert(node);
+        for next in adj(grid, &node) {
+            if seen.contains(&next) {
+                continue;
+            }
+            queue.push_back(next);
+            prev.insert(next, node);
+        }
+    }
+    seen
+}
@@ -0,0 +1,31 @@
+use crate::coords::{self, Expansion};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        Grid::raw(lines).find(|c| c == &'#')
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string() // 10885634
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input, 1000000).to_string()) // 707505470642
+    }
+}
+
+fn part2(stars: &[(usize, usize)], scale: usize) -> usize {
+    let exp = Expansion::new(stars, scale);
+    let stars = stars.iter().map(|star| exp.map(star)).collect::<Vec<_>>();
+    coords::pairwise_manhattan(&stars)
+}
+
+fn part1(stars: &[(usize, usize)]) -> usize {
+    part2(stars, 2)
+}
@@ -0,0 +1,185 @@
+use std::convert::Infallible;
+
+use crate::solution::Solution;
+use crate::*;
+use grid::{Axis, Grid};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string() // 39939
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input).to_string()) // 32069
+    }
+}
+
+fn part1(grids: &[Grid<char>]) -> usize {
+    grids
+        .iter()
+        .filter_map(|grid| {
+            reflection(grid, Axis::Horizontal)
+                .map(|row| (row, 0))
+                .or(reflection(grid, Axis::Vertical).map(|col| (0, col)))
+        })
+        .map(|(rows, cols)| rows * 100 + cols)
+        .sum()
+}
+
+fn reflection(grid: &Grid<char>, axis: Axis) -> Option<usize> {
+    // "add up the number of columns to the left of each vertical line of reflection; ...
+    // add 100 multiplied by the number of rows above each horizontal line of reflection."
+    mirror(grid, axis, 0)
+}
+
+fn part2(grids: &[Grid<char>]) -> usize {
+    grids
+        .iter()
+        .filter_map(|grid| {
+            different_reflection(grid, Axis::Horizontal)
+                .map(|row| (row, 0))
+                .or(different_reflection(grid, Axis::Vertical)
+                    .map(|col| (0, col)))
+        })
+        .map(|(rows, cols)| rows * 100 + cols)
+        .sum()
+}
+
+fn different_reflection(grid: &Grid<char>, axis: Axis) -> Option<usize> {
+    // "In each pattern, you'll need to locate and fix the smudge
+    // that causes a different reflection line to be valid."
+    mirror(grid, axis, 1)
+}
+
+fn mirror(grid: &Grid<char>, axis: Axis, smudges: usize) -> Option<usize> {
+    grid.reflections(axis, smudges)
+        .into_iter()
+        .find(|r| r.diff.len() == smudges)
+        .map(|r| r.line)
+}
+
+fn parse(lines: Vec<String>) -> Vec<Grid<char>> {
+    blocks::grids(&lines, Ok::<char, Infallible>)
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_mirror(row: usize, rows: &[String]) -> bool {
+        Grid::raw(rows.to_vec())
+            .reflections(Axis::Horizontal, 0)
+            .iter()
+            .any(|r| r.line == row)
+    }
+
+    #[test]
+    fn test_is_mirror_2() {
+        let s = r#"
+            #.##..##.
+            ..#.##.#.
+            ##......#
+            ##......#
+            ..#.##.#.
+            ..##..##.
+            #.#.##.#.
+        "#;
+
+        let lines = s
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+
+        let grid = Grid::raw(lines);
+        let (rows, cols) = grid.size();
+        let grid = grid.transpose();
+        assert_eq!(grid.size(), (cols, rows));
+
+        let rows = grid
+            .rows()
+            .map(|cs| cs.iter().collect::<String>())
+            .collect::<Vec<_>>();
+
+        assert!(is_mirror(5, &rows));
+
+        assert!(!is_mirror(0, &rows));
+        assert!(!is_mirror(1, &rows));
+        assert!(!is_mirror(2, &rows));
+        assert!(!is_mirror(3, &rows));
+        assert!(!is_mirror(4, &rows));
+        assert!(!is_mirror(6, &rows));
+        assert!(!is_mirror(7, &rows));
+    }
+
+    #[test]
+    fn test_is_mirror_1() {
+        let s = r#"
+            #...##..#
+            #....#..#
+            ..##..###
+            #####.##.
+            #####.##.
+            ..##..###
+            #....#..#
+        "#;
+
+        let rows = s
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 7);
+
+        assert!(is_mirror(4, &rows));
+
+        assert!(!is_mirror(0, &rows));
+        assert!(!is_mirror(1, &rows));
+        assert!(!is_mirror(2, &rows));
+        assert!(!is_mirror(3, &rows));
+        assert!(!is_mirror(5, &rows));
+        assert!(!is_mirror(6, &rows));
+        assert!(!is_mirror(7, &rows));
+    }
+
+    #[test]
+    fn test_is_mirror_3() {
+        let s = r#"
+            ###.###..#....#..
+            #....#.#.#.#####.
+            #.#....#.....###.
+            .###.##..######.#
+            .....#....#.##.##
+            .....#....#.##.##
+            .###.##..######.#
+            #.#....#.....###.
+            ##...#.#.#.#####.
+            ###.###..#....#..
+            ....#...#.#......
+            ..##.##.#.###.#..
+            ..##.##.#.###.#..
+        "#;
+
+        let rows = s
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 13);
+
+        assert!(is_mirror(12, &rows));
+
+        let grid = Grid::raw(rows);
+        assert_eq!(reflection(&grid, Axis::Horizontal), Some(12));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::solution::Solution;
+use crate::*;
+use grid::{Dir, Grid};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        Grid::raw(lines)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input.clone()).to_string() // 107142
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input.clone()).to_string()) // 104815
+    }
+}
+
+fn part2(grid: Grid<char>) -> usize {
+    const N: usize = 1000000000;
+    let grid = automaton::jump(grid, N, Grid::clone, |mut grid| {
+        cycle(&mut grid);
+        grid
+    });
+    load(&grid)
+}
+
+fn load(grid: &Grid<char>) -> usize {
+    let (rows, _) = grid.size();
+    grid.find(|c| c == &'O')
+        .into_iter()
+        .map(|(row, _)| rows - row)
+        .sum()
+}
+
+fn cycle(grid: &mut Grid<char>) {
+    // "Each cycle tilts the platform four times so that the rounded
+    // rocks roll north, then west, then south, then east."
+    for dir in [Dir::North, Dir::West, Dir::South, Dir::East] {
+        grid.compact(dir, |c| c == &'O', |c| c == &'.');
+    }
+}
+
+fn north(grid: &mut Grid<char>) {
+    grid.compact(Dir::North, |c| c == &'O', |c| c == &'.');
+}
+
+fn part1(mut grid: Grid<char>) -> usize {
+    north(&mut grid);
+    load(&grid)
+}
@@ -0,0 +1,99 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::grid;
+use crate::solution::Solution;
+use grid::{Cell, Dir, Grid};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        Grid::try_new(lines, |c| {
+            c.to_digit(10).map(|d| d as usize).ok_or("not a digit")
+        })
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string() // 963
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input).to_string()) // 1178
+    }
+}
+
+fn part2(grid: &Grid<usize>) -> usize {
+    let (rows, cols) = grid.size();
+    let dst = (rows - 1, cols - 1);
+    bfs(grid, dst, 4, 10) + 1
+}
+
+fn part1(grid: &Grid<usize>) -> usize {
+    let (rows, cols) = grid.size();
+    let dst = (rows - 1, cols - 1);
+    bfs(grid, dst, 1, 3) + 1
+}
+
+fn bfs(grid: &Grid<usize>, dst: Cell, min: usize, max: usize) -> usize {
+    let mut ret = usize::MAX;
+    let mut queue: VecDeque<(usize, Cell, Dir, usize)> = VecDeque::new();
+    queue.push_back((*grid.get(&(0, 1)).unwrap(), (0, 1), Dir::East, 0));
+    queue.push_back((*grid.get(&(1, 0)).unwrap(), (1, 0), Dir::South, 0));
+    let mut seen: HashSet<(Cell, Dir, usize)> = HashSet::new();
+    while let Some(next) = queue.pop_front() {
+        let (loss, cell, dir, steps) = next;
+        if cell == dst {
+            ret = ret.min(loss);
+        }
+        let loss = loss + grid.get(&cell).unwrap();
+        if loss >= ret {
+            continue;
+        }
+        if seen.contains(&(cell, dir, steps)) {
+            continue;
+        } else {
+            seen.insert((cell, dir, steps));
+        }
+        for (next, dir, steps) in exp(grid, &cell, &dir, &steps, min, max) {
+            queue.push_back((loss, next, dir, steps));
+        }
+        queue = sort(queue);
+    }
+    ret
+}
+
+fn exp(
+    grid: &Grid<usize>,
+    cell: &Cell,
+    dir: &Dir,
+    steps: &usize,
+    min: usize,
+    max: usize,
+) -> Vec<(Cell, Dir, usize)> {
+    let mut ret = Vec::with_capacity(4);
+    if *steps + min < max {
+        if let Some(next) = grid.step(cell, *dir, min) {
+            ret.push((next, *dir, *steps + min));
+        }
+    }
+    let cw = dir.cw();
+    if let Some(next) = grid.step(cell, cw, min) {
+        ret.push((next, cw, 4));
+    }
+    let ccw = dir.ccw();
+    if let Some(next) = grid.step(cell, ccw, min) {
+        ret.push((next, ccw, 4));
+    }
+    ret
+}
+
+fn sort(
+    q: VecDeque<(usize, Cell, Dir, usize)>,
+) -> VecDeque<(usize, Cell, Dir, usize)> {
+    let mut v = q.into_iter().collect::<Vec<_>>();
+    v.sort_by_key(|x| x.0);
+    v.into_iter().collect()
+}
@@ -0,0 +1,115 @@
+use crate::solution::Solution;
+use crate::*;
+use geom::Polygon;
+use grid::Dir;
+use std::collections::HashSet;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        lines
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string() // 33491
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(part2(input).to_string()) // 87716969654406
+    }
+}
+
+fn part2(lines: &[String]) -> usize {
+    let steps = lines.iter().map(|line| parse2(line)).map(|(chr, len)| {
+        let dir = Dir::from_char(chr)
+            .unwrap_or_else(|| panic!("unexpected direction: '{chr}'"));
+        (dir, len as u64)
+    });
+    Polygon::from_steps(steps).dug() as usize
+}
+
+fn part1(lines: &[String]) -> usize {
+    let steps = lines.iter().map(|line| parse1(line)).collect::<Vec<_>>();
+    let path = dig1(&steps);
+    // dump(&path);
+    path.len() + grid::interior_count(&path)
+}
+
+fn dig1(steps: &[(char, usize)]) -> Vec<(isize, isize)> {
+    let mut ret = Vec::new();
+    let mut dot = (0, 0);
+    for (chr, len) in steps {
+        let d = Dir::from_char(*chr)
+            .unwrap_or_else(|| panic!("unexpected direction: '{chr}'"))
+            .delta();
+        for _ in 0..*len {
+            dot = (dot.0 + d.0, dot.1 + d.1);
+            ret.push(dot);
+        }
+    }
+    ret
+}
+
+#[allow(dead_code)]
+fn dump(path: &[(isize, isize)]) {
+    let dots = path.iter().collect::<HashSet<_>>();
+    let (rows, cols): (Vec<_>, Vec<_>) = path.iter().cloned().unzip();
+    let (min, max) = (rows.iter().min().unwrap(), rows.iter().max().unwrap());
+    let s = (*min..=*max)
+        .map(|row| {
+            let (min, max) =
+                (cols.iter().min().unwrap(), cols.iter().max().unwrap());
+            (*min..=*max)
+                .map(|col| if dots.contains(&(row, col)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    println!("{s}");
+}
+
+fn parse1(line: &str) -> (char, usize) {
+    let mut it = line.split_whitespace();
+    let chr = it.next().unwrap().chars().next().unwrap();
+    let len = it.next().unwrap().parse().unwrap();
+    (chr, len)
+}
+
+fn parse2(line: &str) -> (char, usize) {
+    let mut it = line.split_whitespace();
+    let hex = it
+        .nth(2)
+        .unwrap()
+        .chars()
+        .filter(|c| c.is_ascii_hexdigit())
+        .collect::<String>();
+    let color = u32::from_str_radix(&hex, 16).unwrap();
+
+    let chr = match color & 0xF {
+        0 => 'R',
+        1 => 'D',
+        2 => 'L',
+        3 => 'U',
+        x => panic!("unsupported direction: {x}"),
+    };
+    let len = color >> 4;
+    (chr, len as usize)
+}
+
+// cargo run --bin aoc -- run 18
+// cargo run --release --bin aoc -- run 18
+// cargo test --package advent-of-code-2023 --lib -- days::day18 --nocapture
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse2() {
+        assert_eq!(parse2("X 0 70c710"), ('R', 461937));
+        assert_eq!(parse2("X 0 0dc571"), ('D', 56407));
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashMap, VecDeque};
+use crate::solution::Solution;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<Name, Gate>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        parse(&lines)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string()
+        // Farewell, AoC. It was fun.
+    }
+}
+
+fn part1(gates: &HashMap<Name, Gate>) -> usize {
+    let mut gates = gates.to_owned();
+    let (mut lo, mut hi) = (0, 0);
+    const N: usize = 1000;
+    for _ in 0..N {
+        let r = hit(&mut gates);
+        lo += r.0;
+        hi += r.1;
+    }
+    lo * hi
+}
+
+pub type Name = [char; 2];
+
+#[derive(Clone, Copy, Debug)]
+pub enum Pulse {
+    Hi,
+    Lo,
+}
+
+#[derive(Clone, Debug)]
+pub enum Gate {
+    FlipFlop {
+        on: bool,
+        inputs: Vec<Name>,
+    },
+    Conjunct {
+        inputs: Vec<Name>,
+        states: HashMap<Name, Pulse>,
+    },
+    Broadcast {
+        inputs: Vec<Name>,
+    }
+}
+
+fn hit(gates: &mut HashMap<Name, Gate>) -> (usize, usize) {
+    let (mut lo, mut hi) = (0, 0);
+    let mut queue: VecDeque<(Name, Pulse, Name)> = VecDeque::new();
+    queue.push_back((BC, Pulse::Lo, OO));
+    while let Some((name, pulse, from)) = queue.pop_front() {
+        match pulse {
+            Pulse::Lo => { lo += 1; }
+            Pulse::Hi => { hi += 1; }
+        };
+        if let Some(gate) = gates.get_mut(&name) {
+            for (to, pulse) in gate.handle(pulse, from) {
+                queue.push_back((to, pulse, name));
+            }    
+        }
+    }
+    // "module configuration" has cycles apparently
+    // panic!("Single hit completed!");
+    (lo, hi)
+}
+
+impl Gate {
+    fn handle(&mut self, pulse: Pulse, from: Name) -> Vec<(Name, Pulse)> {
+        match self {
+            Self::Broadcast { inputs } => {
+                inputs.iter().cloned().map(|input| (input, pulse)).collect()
+            }
+            Self::FlipFlop { on, inputs } if matches!(pulse, Pulse::Lo) => {
+                let p = if *on {
+                    *on = false;
+                    Pulse::Lo
+                } else {
+                    *on = true;
+                    Pulse::Hi
+                };
+                inputs.iter().cloned().map(|input| (input, p)).collect()
+            }
+            Self::Conjunct { inputs, states } => {
+                states.entry(from).or_insert(pulse);
+                let p = if states.values().all(|p| matches!(p, Pulse::Hi)) {
+                    Pulse::Lo
+                } else {
+                    Pulse::Hi
+                };
+                inputs.iter().cloned().map(|input| (input, p)).collect()
+            }
+            _ => vec![]
+        }
+    }
+}
+
+fn parse(lines: &[String]) -> HashMap<Name, Gate> {
+    lines.iter()
+        .map(|line| parse_gate(line))
+        .collect()
+}
+
+const BC: Name = ['B', 'C'];
+const OO: Name = ['0', '0'];
+
+fn parse_gate(line: &str) -> (Name, Gate) {
+    if line.starts_with("broadcaster") {
+        let inputs = line.strip_prefix("broadcaster -> ").unwrap()
+            .split(", ")
+            .map(as_name)
+            .collect();
+        (BC, Gate::Broadcast { inputs })
+    } else if line.starts_with('%') {
+        let mut it = line.strip_prefix('%').unwrap().split(" -> ");
+        let name = as_name(it.next().unwrap());
+        let inputs = it.next().unwrap().split(", ").map(as_name).collect();
+        (name, Gate::FlipFlop { on: false, inputs })
+    } else if line.starts_with('&') {
+        let mut it = line.strip_prefix('&').unwrap().split(" -> ");
+        let name = as_name(it.next().unwrap());
+        let inputs: Vec<Name> = it.next().unwrap().split(", ").map(as_name).collect();
+        let states = HashMap::new();
+        (name, Gate::Conjunct { inputs, states })
+    } else {
+        panic!("invalid gate: {line}");
+    }
+}
+
+fn as_name(chunk: &str) -> Name {
+    assert_eq!(chunk.len(), 2, "invalid chunk: {}", chunk);
+    let fst = chunk.chars().next().unwrap();
+    let snd = chunk.chars().nth(1).unwrap();
+    [fst, snd]
+}
+
+// cargo run --bin aoc -- run 20
+// cargo run --release --bin aoc -- run 20
+// cargo test --package advent-of-code-2023 --lib -- days::day20 --nocapture
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_it() {
+        //
+    }
+}
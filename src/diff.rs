@@ -0,0 +1,105 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use crate::grid::{Cell, Grid};
+
+impl<T: Clone + Debug + PartialEq + 'static> Grid<T> {
+    /// Get cells that differ from another grid of the same size, with the
+    /// old (this grid) and new (other grid) values, by rows
+    pub fn diff(&self, other: &Grid<T>) -> Vec<(Cell, T, T)> {
+        assert_eq!(self.size(), other.size(), "grid size mismatch");
+        self.iter()
+            .zip(other.iter())
+            .filter(|((_, _, a), (_, _, b))| a != b)
+            .map(|((row, col, a), (_, _, b))| {
+                ((row, col), a.clone(), b.clone())
+            })
+            .collect()
+    }
+
+    /// Get textual diff: only changed rows of the other grid are rendered,
+    /// each followed by a line marking changed columns with '^'
+    pub fn diff_dump(
+        &self,
+        other: &Grid<T>,
+        f: impl Fn(&Cell, T) -> char,
+    ) -> String {
+        let diff = self.diff(other);
+        let (rows, cols) = other.size();
+        let width = rows.saturating_sub(1).to_string().len();
+        let mut ret = Vec::new();
+        for (row, line) in other.rows().enumerate() {
+            let changed = diff
+                .iter()
+                .filter(|((r, _), _, _)| *r == row)
+                .map(|((_, col), _, _)| *col)
+                .collect::<Vec<_>>();
+            if changed.is_empty() {
+                continue;
+            }
+            let text = line
+                .iter()
+                .enumerate()
+                .map(|(col, val)| f(&(row, col), val.clone()))
+                .collect::<String>();
+            let marks = (0..cols)
+                .map(|col| if changed.contains(&col) { '^' } else { ' ' })
+                .collect::<String>();
+            ret.push(format!("{row:>width$} | {text}"));
+            ret.push(format!("{:>width$} | {}", "", marks.trim_end()));
+        }
+        ret.join("\n")
+    }
+}
+
+impl<T: Clone + Debug + Hash + 'static> Grid<T> {
+    /// Get 64-bit hash of grid size and contents: a quick pre-check for
+    /// equality only, as distinct grids may collide (key maps on the grid
+    /// itself, which is `Hash + Eq`, where exactness matters)
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap as Map;
+
+    use super::*;
+    use crate::grid::Dir;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::raw(s.lines().map(|line| line.to_owned()).collect())
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = grid("O.#.\n..O.\nO...");
+        let mut b = a.clone();
+        b.compact(Dir::North, |c| c == &'O', |c| c == &'.');
+        assert_eq!(a.diff(&b), vec![((1, 0), '.', 'O'), ((2, 0), 'O', '.')]);
+        assert_eq!(
+            a.diff_dump(&b, |_, c| c),
+            "1 | O.O.\n  | ^\n2 | ....\n  | ^"
+        );
+        assert!(a.diff(&a).is_empty());
+        assert_eq!(a.diff_dump(&a, |_, c| c), "");
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let a = grid("O.#\n..O");
+        let b = grid("O.#\n.O.");
+        assert_eq!(a.fingerprint(), a.clone().fingerprint());
+        assert_ne!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), a.transpose().fingerprint());
+
+        let mut seen: Map<Grid<char>, usize> = Map::new();
+        seen.insert(a.clone(), 0);
+        seen.insert(b, 1);
+        assert_eq!(seen.get(&a), Some(&0));
+    }
+}
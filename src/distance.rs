@@ -0,0 +1,178 @@
+use std::collections::VecDeque as Seq;
+use std::fmt::Debug;
+
+use crate::grid::{Cell, Grid, Neighborhood};
+
+impl<T: Clone + Debug + 'static> Grid<T> {
+    /// Get BFS distance from the nearest source to every cell reachable
+    /// through passable cells (multi-source BFS); unreachable cells are None
+    pub fn distance_field(
+        &self,
+        sources: &[Cell],
+        passable: impl Fn(&T) -> bool,
+        hood: Neighborhood,
+    ) -> Grid<Option<u32>> {
+        let mut dist: Grid<Option<u32>> = Grid::filled(self.size(), None);
+        let mut queue: Seq<Cell> = Seq::new();
+        for src in sources {
+            if let Some(d @ None) = dist.get_mut(src) {
+                *d = Some(0);
+                queue.push_back(*src);
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let d = dist.get(&node).unwrap().unwrap();
+            for (next, _) in self.neighbors(&node, hood) {
+                if dist.get(&next).unwrap().is_some()
+                    || !passable(self.get(&next).unwrap())
+                {
+                    continue;
+                }
+                dist.set(&next, Some(d + 1));
+                queue.push_back(next);
+            }
+        }
+        dist
+    }
+
+    /// Get Manhattan distance from every cell to the nearest cell satisfying
+    /// a predicate, ignoring walls (None if there is no such cell)
+    pub fn manhattan_transform(
+        &self,
+        is_source: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.transform(is_source, &[(-1, 0), (0, -1)])
+    }
+
+    /// Get Chebyshev distance from every cell to the nearest cell satisfying
+    /// a predicate, ignoring walls (None if there is no such cell)
+    pub fn chebyshev_transform(
+        &self,
+        is_source: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.transform(is_source, &[(-1, -1), (-1, 0), (-1, 1), (0, -1)])
+    }
+
+    // Two-pass chamfer transform: forward pass looks at already visited
+    // neighbors above/left, backward pass at the mirrored ones below/right.
+    fn transform(
+        &self,
+        is_source: impl Fn(&T) -> bool,
+        mask: &[(isize, isize)],
+    ) -> Grid<Option<u32>> {
+        let (rows, cols) = self.size();
+        let mut dist = Grid::filled(self.size(), None);
+        for (row, col, val) in self.iter() {
+            if is_source(val) {
+                dist.set(&(row, col), Some(0));
+            }
+        }
+        let cells =
+            (0..rows).flat_map(|row| (0..cols).map(move |col| (row, col)));
+        let forward = cells.clone().collect::<Vec<_>>();
+        let backward = cells.rev().collect::<Vec<_>>();
+        for (cells, sign) in [(forward, 1), (backward, -1)] {
+            for cell in cells {
+                let best = mask
+                    .iter()
+                    .filter_map(|(dr, dc)| {
+                        dist.offset(&cell, (dr * sign, dc * sign))
+                    })
+                    .filter_map(|next| *dist.get(&next).unwrap())
+                    .map(|d: u32| d + 1)
+                    .min();
+                let d = dist.get_mut(&cell).unwrap();
+                *d = match (*d, best) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+        }
+        dist
+    }
+}
+
+/// Get cells reachable in exactly given number of steps (when revisiting is
+/// allowed): distance is within the limit and has the same parity; only
+/// valid for fields built with `Neighborhood::Four`, as diagonal moves break
+/// the parity argument
+pub fn reachable_in(field: &Grid<Option<u32>>, steps: u32) -> Vec<Cell> {
+    field
+        .iter()
+        .filter_map(|(row, col, d)| d.map(|d| ((row, col), d)))
+        .filter(|(_, d)| *d <= steps && d % 2 == steps % 2)
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_field() {
+        let lines = r#"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+..........."#;
+        let grid = Grid::raw(lines.lines().map(|s| s.to_owned()).collect());
+        let start = grid.find(|c| c == &'S');
+        let field =
+            grid.distance_field(&start, |c| c != &'#', Neighborhood::Four);
+        assert_eq!(field.get(&(5, 5)), Some(&Some(0)));
+        assert_eq!(field.get(&(5, 6)), Some(&None));
+        assert_eq!(field.get(&(4, 5)), Some(&Some(1)));
+        assert_eq!(reachable_in(&field, 1).len(), 2);
+        assert_eq!(reachable_in(&field, 2).len(), 4);
+        assert_eq!(reachable_in(&field, 3).len(), 6);
+        assert_eq!(reachable_in(&field, 6).len(), 16);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let grid = Grid::raw(vec!["a...b".to_owned(), ".###.".to_owned()]);
+        let field = grid.distance_field(
+            &[(0, 0), (0, 4)],
+            |c| c != &'#',
+            Neighborhood::Four,
+        );
+        let row = field.rows().next().unwrap().to_vec();
+        assert_eq!(row, vec![Some(0), Some(1), Some(2), Some(1), Some(0)]);
+        assert_eq!(field.get(&(1, 2)), Some(&None));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::raw(vec![
+            "....".to_owned(),
+            ".#..".to_owned(),
+            "....".to_owned(),
+            "...#".to_owned(),
+        ]);
+        let manhattan = grid.manhattan_transform(|c| c == &'#');
+        let chebyshev = grid.chebyshev_transform(|c| c == &'#');
+        for (row, col, _) in grid.iter() {
+            let walls = grid.find(|c| c == &'#');
+            let d1 = walls
+                .iter()
+                .map(|(r, c)| (r.abs_diff(row) + c.abs_diff(col)) as u32)
+                .min();
+            let d2 = walls
+                .iter()
+                .map(|(r, c)| r.abs_diff(row).max(c.abs_diff(col)) as u32)
+                .min();
+            assert_eq!(manhattan.get(&(row, col)), Some(&d1));
+            assert_eq!(chebyshev.get(&(row, col)), Some(&d2));
+        }
+
+        let empty = grid.manhattan_transform(|_| false);
+        assert!(empty.iter().all(|(_, _, d)| d.is_none()));
+    }
+}
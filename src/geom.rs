@@ -0,0 +1,162 @@
+use crate::grid::Dir;
+
+/// Type alias for an integer point (row, column)
+pub type Point = (i64, i64);
+
+/// Integer polygon given by its vertices (closing edge is implied)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Create a new polygon out of vertices
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Create a new polygon by following (direction, length) instructions
+    /// starting at the origin
+    pub fn from_steps(steps: impl IntoIterator<Item = (Dir, u64)>) -> Self {
+        let mut vertices = Vec::new();
+        let mut at: Point = (0, 0);
+        for (dir, len) in steps {
+            let (drow, dcol) = dir.delta();
+            let len = len as i64;
+            at = (at.0 + drow as i64 * len, at.1 + dcol as i64 * len);
+            vertices.push(at);
+        }
+        Self { vertices }
+    }
+
+    /// Get polygon vertices
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Iterate over polygon edges (including the closing one)
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Get twice the signed area (shoelace formula): negative when vertices
+    /// go clockwise as seen on screen (rows down, columns right)
+    pub fn signed_area2(&self) -> i128 {
+        self.edges()
+            .map(|((r1, c1), (r2, c2))| {
+                r1 as i128 * c2 as i128 - r2 as i128 * c1 as i128
+            })
+            .sum()
+    }
+
+    /// Get twice the area enclosed by the polygon (always exact)
+    pub fn area2(&self) -> i128 {
+        self.signed_area2().abs()
+    }
+
+    /// Count lattice points on the polygon boundary
+    pub fn boundary(&self) -> i128 {
+        self.edges()
+            .map(|((r1, c1), (r2, c2))| {
+                gcd((r2 - r1).unsigned_abs(), (c2 - c1).unsigned_abs()) as i128
+            })
+            .sum()
+    }
+
+    /// Count lattice points strictly inside the polygon (Pick's theorem);
+    /// zero for a polygon with no boundary (no vertices or a single point)
+    pub fn interior(&self) -> i128 {
+        let boundary = self.boundary();
+        if boundary == 0 {
+            return 0;
+        }
+        // A = I + B/2 - 1
+        (self.area2() - boundary + 2) / 2
+    }
+
+    /// Count unit cells covered by the polygon when each lattice point is
+    /// a cell: both the boundary (trench) and the interior
+    pub fn dug(&self) -> i128 {
+        self.interior() + self.boundary()
+    }
+}
+
+/// Greatest common divisor
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day18() -> Vec<(Dir, u64)> {
+        "R6 D5 L2 D2 R2 D2 L5 U2 L1 U2 R2 U3 L2 U2"
+            .split_whitespace()
+            .map(|s| {
+                let dir = Dir::from_char(s.chars().next().unwrap()).unwrap();
+                (dir, s[1..].parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_square() {
+        let square = Polygon::new(vec![(0, 0), (0, 2), (2, 2), (2, 0)]);
+        assert_eq!(square.area2(), 8);
+        assert_eq!(square.boundary(), 8);
+        assert_eq!(square.interior(), 1);
+        assert_eq!(square.dug(), 9);
+        assert_eq!(square.signed_area2(), -8);
+
+        let triangle = Polygon::new(vec![(0, 0), (3, 3), (0, 6)]);
+        assert_eq!(triangle.area2(), 18);
+        assert_eq!(triangle.boundary(), 12);
+        assert_eq!(triangle.interior(), 4);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        for poly in [Polygon::new(vec![]), Polygon::new(vec![(3, 3)])] {
+            assert_eq!(poly.area2(), 0);
+            assert_eq!(poly.boundary(), 0);
+            assert_eq!(poly.interior(), 0);
+            assert_eq!(poly.dug(), 0);
+        }
+    }
+
+    #[test]
+    fn test_from_steps() {
+        let poly = Polygon::from_steps(day18());
+        assert_eq!(poly.vertices().len(), 14);
+        assert_eq!(poly.vertices().last(), Some(&(0, 0)));
+        assert_eq!(poly.boundary(), 38);
+        assert_eq!(poly.dug(), 62);
+    }
+
+    #[test]
+    fn test_large() {
+        let poly = Polygon::from_steps([
+            (Dir::East, 461937),
+            (Dir::South, 56407),
+            (Dir::East, 356671),
+            (Dir::South, 863240),
+            (Dir::East, 367720),
+            (Dir::South, 266681),
+            (Dir::West, 577262),
+            (Dir::North, 829975),
+            (Dir::West, 112010),
+            (Dir::South, 829975),
+            (Dir::West, 491645),
+            (Dir::North, 686074),
+            (Dir::West, 5411),
+            (Dir::North, 500254),
+        ]);
+        assert_eq!(poly.dug(), 952408144115);
+    }
+}
@@ -139,6 +139,6 @@ pub fn dijkstra(g: &Graph, n: usize) -> (Map<usize, f64>, Map<usize, usize>) {
 // TODO dfs
 
 #[cfg(test)]
-mod graf {
+mod tests {
     // TODO add tests
 }
@@ -1,17 +1,62 @@
 use std::collections::{HashMap as Map, HashSet as Set, VecDeque as Seq};
-use std::fmt::Debug;
+use std::convert::Infallible;
+use std::fmt::{self, Debug, Display};
+
+use crate::geom::Polygon;
 
 /// Type alias for a cell positio (row, column)
 pub type Cell = (usize, usize);
 
 /// Generic dense grid implementation
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Grid<T: Clone + Debug + 'static> {
     rows: usize,
     cols: usize,
     data: Vec<Vec<T>>,
 }
 
+/// Error produced by fallible grid construction
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GridError<E> {
+    /// Input contains no lines
+    Empty,
+    /// Row length differs from the length of the first row
+    Ragged {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Cell mapper rejected a character
+    Cell {
+        row: usize,
+        col: usize,
+        chr: char,
+        err: E,
+    },
+}
+
+impl<E: Display> Display for GridError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::Ragged {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "grid rows have different lengths: \
+                row {row} has {actual} cells, expected {expected}"
+            ),
+            GridError::Cell { row, col, chr, err } => {
+                write!(f, "invalid cell '{chr}' at ({row}, {col}): {err}")
+            }
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for GridError<E> {}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Dir {
     North,
@@ -46,6 +91,165 @@ impl Dir {
     pub fn ccw(&self) -> Self {
         self.cw().inv()
     }
+
+    /// Get (row, column) offset of a single step in this direction
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Dir::North => (-1, 0),
+            Dir::East => (0, 1),
+            Dir::South => (1, 0),
+            Dir::West => (0, -1),
+        }
+    }
+
+    /// Parse direction from one of `^>v<`, `UDLR` or `NESW`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' | 'N' => Some(Dir::North),
+            '>' | 'R' | 'E' => Some(Dir::East),
+            'v' | 'D' | 'S' => Some(Dir::South),
+            '<' | 'L' | 'W' => Some(Dir::West),
+            _ => None,
+        }
+    }
+}
+
+/// Direction including diagonals, ordered clockwise starting from north
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    pub fn all() -> [Dir8; 8] {
+        [
+            Dir8::North,
+            Dir8::NorthEast,
+            Dir8::East,
+            Dir8::SouthEast,
+            Dir8::South,
+            Dir8::SouthWest,
+            Dir8::West,
+            Dir8::NorthWest,
+        ]
+    }
+
+    /// Turn by a given number of 45-degree steps (positive is clockwise)
+    pub fn rot(&self, steps: isize) -> Self {
+        let idx = Self::all().iter().position(|d| d == self).unwrap();
+        Self::all()[(idx as isize + steps).rem_euclid(8) as usize]
+    }
+
+    /// Turn 45 degrees clockwise
+    pub fn cw(&self) -> Self {
+        self.rot(1)
+    }
+
+    /// Turn 45 degrees counter-clockwise
+    pub fn ccw(&self) -> Self {
+        self.rot(-1)
+    }
+
+    pub fn inv(&self) -> Self {
+        self.rot(4)
+    }
+
+    /// Check if direction is one of the four cardinal ones
+    pub fn is_cardinal(&self) -> bool {
+        self.as_dir().is_some()
+    }
+
+    /// Convert to cardinal direction (if not diagonal)
+    pub fn as_dir(&self) -> Option<Dir> {
+        match self {
+            Dir8::North => Some(Dir::North),
+            Dir8::East => Some(Dir::East),
+            Dir8::South => Some(Dir::South),
+            Dir8::West => Some(Dir::West),
+            _ => None,
+        }
+    }
+
+    /// Get (row, column) offset of a single step in this direction
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Dir8::North => (-1, 0),
+            Dir8::NorthEast => (-1, 1),
+            Dir8::East => (0, 1),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (1, 0),
+            Dir8::SouthWest => (1, -1),
+            Dir8::West => (0, -1),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Parse direction from one of `^>v<`, `UDLR` or `NESW`
+    pub fn from_char(c: char) -> Option<Self> {
+        Dir::from_char(c).map(Dir8::from)
+    }
+
+    /// Parse direction from `N`, `NE`, `E`, ... (case insensitive)
+    pub fn from_name(s: &str) -> Option<Self> {
+        let s = s.to_ascii_uppercase();
+        Self::all().into_iter().find(|d| {
+            let name = match d {
+                Dir8::North => "N",
+                Dir8::NorthEast => "NE",
+                Dir8::East => "E",
+                Dir8::SouthEast => "SE",
+                Dir8::South => "S",
+                Dir8::SouthWest => "SW",
+                Dir8::West => "W",
+                Dir8::NorthWest => "NW",
+            };
+            name == s
+        })
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::North => Dir8::North,
+            Dir::East => Dir8::East,
+            Dir::South => Dir8::South,
+            Dir::West => Dir8::West,
+        }
+    }
+}
+
+/// Set of neighbors considered adjacent to a cell
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Neighborhood {
+    /// Cardinal directions only (von Neumann)
+    Four,
+    /// Cardinal and diagonal directions (Moore)
+    Eight,
+}
+
+impl Neighborhood {
+    /// Get directions that make up the neighborhood
+    pub fn dirs(&self) -> Vec<Dir8> {
+        match self {
+            Neighborhood::Four => {
+                Dir::all().into_iter().map(Dir8::from).collect()
+            }
+            Neighborhood::Eight => Dir8::all().to_vec(),
+        }
+    }
+
+    /// Get (row, column) offsets that make up the neighborhood
+    pub fn deltas(&self) -> Vec<Pos> {
+        self.dirs().into_iter().map(|dir| dir.delta()).collect()
+    }
 }
 
 impl Grid<char> {
@@ -56,30 +260,97 @@ impl Grid<char> {
 }
 
 impl<T: Clone + Debug + 'static> Grid<T> {
-    /// Create a new grid out of lines
+    /// Create a new grid out of lines (panics on invalid input); rows are
+    /// measured in chars, not bytes, and lines with no cells at all (e.g. a
+    /// single empty line) are rejected as an empty grid
     pub fn new(lines: Vec<String>, f: impl Fn(char) -> T) -> Self {
-        let rows = lines.len();
-        if rows == 0 {
-            panic!("grid is empty");
-        }
+        Self::try_new(lines, |c| Ok::<T, Infallible>(f(c)))
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        let cols = lines.iter().map(|line| line.len()).collect::<Set<_>>();
-        if cols.len() > 1 {
-            panic!("grid rows have different lengths");
+    /// Create a new grid out of lines with a fallible cell mapper
+    pub fn try_new<E>(
+        lines: Vec<String>,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, GridError<E>> {
+        let expected = lines
+            .first()
+            .map(|line| line.chars().count())
+            .ok_or(GridError::Empty)?;
+        for (row, line) in lines.iter().enumerate() {
+            let actual = line.chars().count();
+            if actual != expected {
+                return Err(GridError::Ragged {
+                    row,
+                    expected,
+                    actual,
+                });
+            }
         }
+        Self::build(lines, expected, None, f)
+    }
 
-        let cols = cols.into_iter().next().unwrap();
+    /// Create a new grid out of lines, padding shorter rows with `pad`
+    pub fn try_padded<E>(
+        lines: Vec<String>,
+        pad: char,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, GridError<E>> {
+        let cols = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .ok_or(GridError::Empty)?;
+        Self::build(lines, cols, Some(pad), f)
+    }
+
+    fn build<E>(
+        lines: Vec<String>,
+        cols: usize,
+        pad: Option<char>,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, GridError<E>> {
+        if cols == 0 {
+            return Err(GridError::Empty);
+        }
+        let rows = lines.len();
+        let mut data = Vec::with_capacity(rows);
+        for (row, line) in lines.into_iter().enumerate() {
+            let chars = line.chars().chain(pad.into_iter().cycle());
+            let mut cells = Vec::with_capacity(cols);
+            for (col, chr) in chars.take(cols).enumerate() {
+                let val = f(chr).map_err(|err| GridError::Cell {
+                    row,
+                    col,
+                    chr,
+                    err,
+                })?;
+                cells.push(val);
+            }
+            data.push(cells);
+        }
+        Ok(Self { rows, cols, data })
+    }
 
+    /// Create a new grid of a given size (rows, cols) filled with a value
+    pub fn filled(size: (usize, usize), val: T) -> Self {
+        let (rows, cols) = size;
         Self {
             rows,
             cols,
-            data: lines
-                .into_iter()
-                .map(|line| line.chars().map(&f).collect())
-                .collect(),
+            data: vec![vec![val; cols]; rows],
         }
     }
 
+    // Build a grid of a given size out of rows of `cols` cells each.
+    pub(crate) fn from_rows(size: (usize, usize), data: Vec<Vec<T>>) -> Self {
+        let (rows, cols) = size;
+        debug_assert!(
+            data.len() == rows && data.iter().all(|r| r.len() == cols)
+        );
+        Self { rows, cols, data }
+    }
+
     /// Get grid size (rows, cols)
     pub fn size(&self) -> (usize, usize) {
         (self.rows, self.cols)
@@ -120,6 +391,116 @@ impl<T: Clone + Debug + 'static> Grid<T> {
         }
     }
 
+    /// Get cell at a given (row, column) offset from current position
+    pub fn offset(&self, pos: &Cell, delta: (isize, isize)) -> Option<Cell> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        if row < self.rows && col < self.cols {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Get cell `n` steps away from current position in a given direction
+    pub fn step(
+        &self,
+        pos: &Cell,
+        dir: impl Into<Dir8>,
+        n: usize,
+    ) -> Option<Cell> {
+        let (drow, dcol) = dir.into().delta();
+        let n = isize::try_from(n).ok()?;
+        self.offset(pos, (drow.checked_mul(n)?, dcol.checked_mul(n)?))
+    }
+
+    /// Iterate over cells in a given direction (excluding starting position)
+    /// until the edge of the grid
+    pub fn ray(
+        &self,
+        pos: &Cell,
+        dir: impl Into<Dir8>,
+    ) -> impl Iterator<Item = Cell> + '_ {
+        let delta = dir.into().delta();
+        std::iter::successors(Some(*pos), move |at| self.offset(at, delta))
+            .skip(1)
+    }
+
+    /// Iterate over cells in a given direction until the edge of the grid or
+    /// until (and including) the first cell matching a blocking predicate
+    pub fn ray_until<'a>(
+        &'a self,
+        pos: &Cell,
+        dir: impl Into<Dir8>,
+        blocked: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Cell> + 'a {
+        let mut done = false;
+        self.ray(pos, dir).take_while(move |at| {
+            let ret = !done;
+            done = blocked(self.get(at).unwrap());
+            ret
+        })
+    }
+
+    /// Get cells on a straight line between two positions (inclusive),
+    /// using Bresenham's line algorithm; positions are not bounds-checked
+    pub fn line(&self, from: &Cell, to: &Cell) -> Vec<Cell> {
+        let (mut row, mut col) = (from.0 as isize, from.1 as isize);
+        let (dst_row, dst_col) = (to.0 as isize, to.1 as isize);
+        let drow = (dst_row - row).abs();
+        let dcol = -(dst_col - col).abs();
+        let srow = (dst_row - row).signum();
+        let scol = (dst_col - col).signum();
+        let mut err = drow + dcol;
+        let mut ret = Vec::with_capacity((drow - dcol + 1) as usize);
+        loop {
+            ret.push((row as usize, col as usize));
+            if row == dst_row && col == dst_col {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dcol {
+                err += dcol;
+                row += srow;
+            }
+            if e2 <= drow {
+                err += drow;
+                col += scol;
+            }
+        }
+        ret
+    }
+
+    /// Check if no cell strictly between two positions is opaque (false if
+    /// either position is outside the grid)
+    pub fn visible(
+        &self,
+        from: &Cell,
+        to: &Cell,
+        opaque: impl Fn(&T) -> bool,
+    ) -> bool {
+        if self.get(from).is_none() || self.get(to).is_none() {
+            return false;
+        }
+        let line = self.line(from, to);
+        line.iter()
+            .skip(1)
+            .take(line.len().saturating_sub(2))
+            .all(|at| !opaque(self.get(at).unwrap()))
+    }
+
+    /// Get neighbors of a given position along with direction to each
+    pub fn neighbors(
+        &self,
+        pos: &Cell,
+        hood: Neighborhood,
+    ) -> Vec<(Cell, Dir8)> {
+        hood.dirs()
+            .into_iter()
+            .filter_map(|dir| self.offset(pos, dir.delta()).map(|n| (n, dir)))
+            .collect()
+    }
+
     /// Get adjacent positions to a given one
     pub fn adj(&self, pos: &Cell) -> Vec<Cell> {
         let prow = pos.0 as isize;
@@ -161,6 +542,131 @@ impl<T: Clone + Debug + 'static> Grid<T> {
         ret
     }
 
+    /// Slide all movable cells as far as possible in a given direction over
+    /// empty cells (other cells block), one pass per line; returns number
+    /// of cells that moved
+    pub fn compact(
+        &mut self,
+        dir: Dir,
+        movable: impl Fn(&T) -> bool,
+        empty: impl Fn(&T) -> bool,
+    ) -> usize {
+        let (rows, cols) = self.size();
+        let (lines, len) = match dir {
+            Dir::North | Dir::South => (cols, rows),
+            Dir::East | Dir::West => (rows, cols),
+        };
+        // position of i-th cell of a line counting from the edge cells move to
+        let at = |line: usize, i: usize| match dir {
+            Dir::North => (i, line),
+            Dir::South => (rows - 1 - i, line),
+            Dir::West => (line, i),
+            Dir::East => (line, cols - 1 - i),
+        };
+        let mut moved = 0;
+        for line in 0..lines {
+            let mut free = 0;
+            for i in 0..len {
+                let (row, col) = at(line, i);
+                let val = &self.data[row][col];
+                if movable(val) {
+                    if free < i {
+                        let (r, c) = at(line, free);
+                        let tmp = self.data[r][c].clone();
+                        self.data[r][c] = self.data[row][col].clone();
+                        self.data[row][col] = tmp;
+                        moved += 1;
+                    }
+                    free += 1;
+                } else if !empty(val) {
+                    free = i + 1;
+                }
+            }
+        }
+        moved
+    }
+
+    /// Get view of the grid repeated infinitely in every direction
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
+
+    /// Get view of the grid with opposite edges glued together
+    pub fn torus(&self) -> Torus<'_, T> {
+        Torus { grid: self }
+    }
+
+    /// Find all cells reachable from a given one through passable cells
+    pub fn flood_fill(
+        &self,
+        from: &Cell,
+        hood: Neighborhood,
+        passable: impl Fn(&T) -> bool,
+    ) -> Set<Cell> {
+        let mut seen: Set<Cell> = Set::new();
+        if !self.get(from).map(&passable).unwrap_or_default() {
+            return seen;
+        }
+        let mut queue: Seq<Cell> = Seq::new();
+        seen.insert(*from);
+        queue.push_back(*from);
+        while let Some(node) = queue.pop_front() {
+            for (next, _) in self.neighbors(&node, hood) {
+                if passable(self.get(&next).unwrap()) && seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Label connected regions of cells, where adjacent cells belong to the
+    /// same region if they satisfy a predicate; returns grid of region
+    /// labels (numbered from 0 in row-major order) and number of regions
+    pub fn label_regions(
+        &self,
+        hood: Neighborhood,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        let mut labels = Grid::filled(self.size(), usize::MAX);
+        let mut count = 0;
+        let mut queue: Seq<Cell> = Seq::new();
+        for (row, col, _) in self.iter() {
+            if labels.data[row][col] != usize::MAX {
+                continue;
+            }
+            labels.set(&(row, col), count);
+            queue.push_back((row, col));
+            while let Some(node) = queue.pop_front() {
+                let this = self.get(&node).unwrap();
+                for (next, _) in self.neighbors(&node, hood) {
+                    if labels.get(&next) == Some(&usize::MAX)
+                        && same_region(this, self.get(&next).unwrap())
+                    {
+                        labels.set(&next, count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (labels, count)
+    }
+
+    /// Get connected regions of cells (see `label_regions`)
+    pub fn regions(
+        &self,
+        hood: Neighborhood,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Vec<Set<Cell>> {
+        let (labels, count) = self.label_regions(hood, same_region);
+        let mut ret = vec![Set::new(); count];
+        for (row, col, label) in labels.iter() {
+            ret[*label].insert((row, col));
+        }
+        ret
+    }
+
     /// Iterate over grid cells by rows, each row by column
     pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
         GridIter::new(self)
@@ -200,6 +706,154 @@ impl<T: Clone + Debug + 'static> Grid<T> {
             data,
         }
     }
+
+    /// Rotate the grid 90 degrees clockwise
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap((self.cols, self.rows), |(row, col)| (rows - 1 - col, row))
+    }
+
+    /// Rotate the grid 90 degrees counter-clockwise
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let cols = self.cols;
+        self.remap((self.cols, self.rows), |(row, col)| (col, cols - 1 - row))
+    }
+
+    /// Rotate the grid 180 degrees
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (rows, cols) = self.size();
+        self.remap(self.size(), |(row, col)| (rows - 1 - row, cols - 1 - col))
+    }
+
+    /// Mirror the grid left to right (reverse each row)
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let cols = self.cols;
+        self.remap(self.size(), |(row, col)| (row, cols - 1 - col))
+    }
+
+    /// Mirror the grid top to bottom (reverse order of rows)
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap(self.size(), |(row, col)| (rows - 1 - row, col))
+    }
+
+    /// Iterate over all 8 rotations and reflections (dihedral group D4),
+    /// starting with the grid itself
+    pub fn dihedral(&self) -> impl Iterator<Item = Grid<T>> {
+        let mut grid = self.clone();
+        (0..8).map(move |i| {
+            let ret = grid.clone();
+            grid = if i == 3 {
+                grid.rotate_cw().flip_horizontal()
+            } else {
+                grid.rotate_cw()
+            };
+            ret
+        })
+    }
+
+    /// Transpose the square grid in place (panics if grid is not square)
+    pub fn transpose_mut(&mut self) {
+        assert_eq!(self.rows, self.cols, "grid must be square");
+        for row in 0..self.rows {
+            for col in (row + 1)..self.cols {
+                let (top, bottom) = self.data.split_at_mut(col);
+                std::mem::swap(&mut top[row][col], &mut bottom[0][row]);
+            }
+        }
+    }
+
+    /// Rotate the square grid 90 degrees clockwise in place
+    pub fn rotate_cw_mut(&mut self) {
+        self.transpose_mut();
+        self.flip_horizontal_mut();
+    }
+
+    /// Rotate the square grid 90 degrees counter-clockwise in place
+    pub fn rotate_ccw_mut(&mut self) {
+        self.transpose_mut();
+        self.flip_vertical_mut();
+    }
+
+    /// Rotate the grid 180 degrees in place
+    pub fn rotate_180_mut(&mut self) {
+        self.flip_horizontal_mut();
+        self.flip_vertical_mut();
+    }
+
+    /// Mirror the grid left to right in place
+    pub fn flip_horizontal_mut(&mut self) {
+        self.data.iter_mut().for_each(|row| row.reverse());
+    }
+
+    /// Mirror the grid top to bottom in place
+    pub fn flip_vertical_mut(&mut self) {
+        self.data.reverse();
+    }
+
+    // Build a grid of a given size, taking each cell from a source position.
+    fn remap(&self, size: (usize, usize), f: impl Fn(Cell) -> Cell) -> Grid<T> {
+        let (rows, cols) = size;
+        let data = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| self.get(&f((row, col))).unwrap().clone())
+                    .collect()
+            })
+            .collect();
+        Grid { rows, cols, data }
+    }
+}
+
+/// Orientation of a mirror line
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Axis {
+    /// Line between two rows
+    Horizontal,
+    /// Line between two columns
+    Vertical,
+}
+
+/// Mirror line of a grid, `line` is the number of rows above (horizontal)
+/// or columns to the left (vertical) of it, `diff` holds mismatched pairs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub line: usize,
+    pub diff: Vec<(Cell, Cell)>,
+}
+
+impl<T: Clone + Debug + PartialEq + 'static> Grid<T> {
+    /// Find all mirror lines along an axis with at most `tolerance`
+    /// mismatched cell pairs
+    pub fn reflections(&self, axis: Axis, tolerance: usize) -> Vec<Reflection> {
+        let (len, span) = match axis {
+            Axis::Horizontal => (self.rows, self.cols),
+            Axis::Vertical => (self.cols, self.rows),
+        };
+        let cell = |i: usize, j: usize| match axis {
+            Axis::Horizontal => (i, j),
+            Axis::Vertical => (j, i),
+        };
+        (1..len)
+            .filter_map(|line| {
+                let mut diff = Vec::new();
+                for d in 0..line.min(len - line) {
+                    for j in 0..span {
+                        let a = cell(line - d - 1, j);
+                        let b = cell(line + d, j);
+                        if self.get(&a) != self.get(&b) {
+                            diff.push((a, b));
+                            if diff.len() > tolerance {
+                                return None;
+                            }
+                        }
+                    }
+                }
+                Some(Reflection { axis, line, diff })
+            })
+            .collect()
+    }
 }
 
 struct GridIter<'a, T: Clone + Debug + 'static> {
@@ -236,6 +890,123 @@ impl<'a, T: Clone + Debug + 'static> Iterator for GridIter<'a, T> {
     }
 }
 
+/// Type alias for a signed position (row, column) on an unbounded plane
+pub type Pos = (isize, isize);
+
+/// Infinitely tiled view of a grid: every position is distinct, and maps
+/// onto the base grid modulo its size
+#[derive(Clone, Copy, Debug)]
+pub struct Tiled<'a, T: Clone + Debug + 'static> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Clone + Debug + 'static> Tiled<'a, T> {
+    /// Get the base grid cell a given position maps onto (None only for an
+    /// empty base grid)
+    pub fn wrap(&self, pos: &Pos) -> Option<Cell> {
+        wrap(self.grid.size(), pos)
+    }
+
+    /// Get (row, column) index of the tile a given position belongs to (None
+    /// only for an empty base grid)
+    pub fn tile(&self, pos: &Pos) -> Option<Pos> {
+        let (rows, cols) = self.grid.size();
+        if rows == 0 || cols == 0 {
+            return None;
+        }
+        Some((
+            pos.0.div_euclid(rows as isize),
+            pos.1.div_euclid(cols as isize),
+        ))
+    }
+
+    /// Get grid item at a given position (None only for an empty base grid)
+    pub fn get(&self, pos: &Pos) -> Option<&'a T> {
+        self.grid.get(&self.wrap(pos)?)
+    }
+
+    /// Get next position given current position and direction
+    pub fn next(&self, pos: &Pos, dir: impl Into<Dir8>) -> Option<Pos> {
+        let (drow, dcol) = dir.into().delta();
+        Some((pos.0.checked_add(drow)?, pos.1.checked_add(dcol)?))
+    }
+
+    /// Get neighbors of a given position along with direction to each
+    pub fn neighbors(&self, pos: &Pos, hood: Neighborhood) -> Vec<(Pos, Dir8)> {
+        hood.dirs()
+            .into_iter()
+            .filter_map(|dir| self.next(pos, dir).map(|n| (n, dir)))
+            .collect()
+    }
+}
+
+/// Toroidal view of a grid: moving over an edge comes back on the
+/// opposite side, positions always stay within the base grid
+#[derive(Clone, Copy, Debug)]
+pub struct Torus<'a, T: Clone + Debug + 'static> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Clone + Debug + 'static> Torus<'a, T> {
+    /// Get the base grid cell a given signed position maps onto (None only
+    /// for an empty base grid)
+    pub fn wrap(&self, pos: &Pos) -> Option<Cell> {
+        wrap(self.grid.size(), pos)
+    }
+
+    /// Get grid item at a given signed position (None only for an empty
+    /// base grid)
+    pub fn get(&self, pos: &Pos) -> Option<&'a T> {
+        self.grid.get(&self.wrap(pos)?)
+    }
+
+    /// Get next cell given current position and direction
+    pub fn next(&self, pos: &Cell, dir: impl Into<Dir8>) -> Option<Cell> {
+        self.step(pos, dir, 1)
+    }
+
+    /// Get cell `n` steps away from current position in a given direction
+    pub fn step(
+        &self,
+        pos: &Cell,
+        dir: impl Into<Dir8>,
+        n: usize,
+    ) -> Option<Cell> {
+        let (drow, dcol) = dir.into().delta();
+        let n = isize::try_from(n).ok()?;
+        let row = isize::try_from(pos.0)
+            .ok()?
+            .checked_add(drow.checked_mul(n)?)?;
+        let col = isize::try_from(pos.1)
+            .ok()?
+            .checked_add(dcol.checked_mul(n)?)?;
+        self.wrap(&(row, col))
+    }
+
+    /// Get neighbors of a given position along with direction to each
+    pub fn neighbors(
+        &self,
+        pos: &Cell,
+        hood: Neighborhood,
+    ) -> Vec<(Cell, Dir8)> {
+        hood.dirs()
+            .into_iter()
+            .filter_map(|dir| self.next(pos, dir).map(|n| (n, dir)))
+            .collect()
+    }
+}
+
+fn wrap(size: (usize, usize), pos: &Pos) -> Option<Cell> {
+    let (rows, cols) = size;
+    if rows == 0 || cols == 0 {
+        return None;
+    }
+    Some((
+        pos.0.rem_euclid(rows as isize) as usize,
+        pos.1.rem_euclid(cols as isize) as usize,
+    ))
+}
+
 /// BFS traversal of a grid
 pub fn bfs<T: Clone + Debug + 'static>(
     grid: &Grid<T>,
@@ -244,19 +1015,116 @@ pub fn bfs<T: Clone + Debug + 'static>(
 ) {
     let mut seen: Set<Cell> = Set::new();
     let mut queue: Seq<Cell> = Seq::new();
+    seen.insert(*from);
     queue.push_back(*from);
-    while !queue.is_empty() {
-        let node = queue.pop_front().unwrap();
-        seen.insert(node);
+    while let Some(node) = queue.pop_front() {
         for next in grid.adj(&node) {
             f(node, next);
-            if !seen.contains(&next) {
+            if seen.insert(next) {
                 queue.push_back(next);
             }
         }
     }
 }
 
+/// Find cells enclosed by a closed loop of orthogonally adjacent positions
+/// (scanline parity: crossing a loop cell connected to the north toggles)
+pub fn interior(path: &[Pos]) -> Set<Pos> {
+    let n = path.len();
+    let on_loop = path.iter().cloned().collect::<Set<_>>();
+    let north = (0..n)
+        .filter(|&i| {
+            let (row, col) = path[i];
+            let prev = path[(i + n - 1) % n];
+            let next = path[(i + 1) % n];
+            prev == (row - 1, col) || next == (row - 1, col)
+        })
+        .map(|i| path[i])
+        .collect::<Set<_>>();
+
+    let mut ret = Set::new();
+    let Some((min, max)) = bounds(path) else {
+        return ret;
+    };
+    for row in min.0..=max.0 {
+        let mut inside = false;
+        for col in min.1..=max.1 {
+            let pos = (row, col);
+            if north.contains(&pos) {
+                inside = !inside;
+            } else if inside && !on_loop.contains(&pos) {
+                ret.insert(pos);
+            }
+        }
+    }
+    ret
+}
+
+/// Count cells enclosed by a closed loop of orthogonally adjacent positions
+/// (shoelace formula for the area and Pick's theorem for interior points)
+pub fn interior_count(path: &[Pos]) -> usize {
+    let vertices = path
+        .iter()
+        .map(|(row, col)| (*row as i64, *col as i64))
+        .collect();
+    Polygon::new(vertices).interior().max(0) as usize
+}
+
+// Get bounding box (min, max) of given positions.
+fn bounds(path: &[Pos]) -> Option<(Pos, Pos)> {
+    let min = path.iter().cloned().reduce(|(arow, acol), (brow, bcol)| {
+        (arow.min(brow), acol.min(bcol))
+    })?;
+    let max = path.iter().cloned().reduce(|(arow, acol), (brow, bcol)| {
+        (arow.max(brow), acol.max(bcol))
+    })?;
+    Some((min, max))
+}
+
+/// Count cell edges on the boundary of a region
+pub fn perimeter(region: &Set<Cell>) -> usize {
+    region
+        .iter()
+        .map(|cell| {
+            Dir::all()
+                .into_iter()
+                .filter(|dir| !region.contains(&shift(cell, (*dir).into())))
+                .count()
+        })
+        .sum()
+}
+
+/// Count straight sides of a region boundary (equal to the number of corners)
+pub fn sides(region: &Set<Cell>) -> usize {
+    let has = |cell: &Cell, dir: Dir8| region.contains(&shift(cell, dir));
+    region
+        .iter()
+        .map(|cell| {
+            Dir::all()
+                .into_iter()
+                .filter(|dir| {
+                    let a = Dir8::from(*dir);
+                    let b = Dir8::from(dir.cw());
+                    let convex = !has(cell, a) && !has(cell, b);
+                    let concave =
+                        has(cell, a) && has(cell, b) && !has(cell, a.cw());
+                    convex || concave
+                })
+                .count()
+        })
+        .sum()
+}
+
+// Move a cell one step in a direction, wrapping past zero (the result is
+// only used for membership checks, never for grid access).
+fn shift(cell: &Cell, dir: Dir8) -> Cell {
+    let (drow, dcol) = dir.delta();
+    (
+        cell.0.wrapping_add_signed(drow),
+        cell.1.wrapping_add_signed(dcol),
+    )
+}
+
 /// Dijkstra's shortest path algorithm
 pub fn dijkstra<T: Clone + Debug + 'static>(
     grid: &Grid<T>,
@@ -298,5 +1166,409 @@ mod tests {
         assert_eq!(grid.transpose().dump(|_, x| x), "149\n25A\n37B\n48C");
     }
 
+    #[test]
+    fn test_rotate_flip() {
+        let grid = Grid::raw(vec!["123".to_owned(), "456".to_owned()]);
+        assert_eq!(grid.rotate_cw().dump(|_, x| x), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().dump(|_, x| x), "36\n25\n14");
+        assert_eq!(grid.rotate_180().dump(|_, x| x), "654\n321");
+        assert_eq!(grid.flip_horizontal().dump(|_, x| x), "321\n654");
+        assert_eq!(grid.flip_vertical().dump(|_, x| x), "456\n123");
+
+        let all = grid
+            .dihedral()
+            .map(|g| g.dump(|_, x| x))
+            .collect::<Set<_>>();
+        assert_eq!(all.len(), 8);
+        assert!(all.contains(&grid.transpose().dump(|_, x| x)));
+    }
+
+    #[test]
+    fn test_rotate_mut() {
+        let grid = Grid::raw(vec![
+            "123".to_owned(),
+            "456".to_owned(),
+            "789".to_owned(),
+        ]);
+
+        let mut g = grid.clone();
+        g.rotate_cw_mut();
+        assert_eq!(g.dump(|_, x| x), grid.rotate_cw().dump(|_, x| x));
+
+        let mut g = grid.clone();
+        g.rotate_ccw_mut();
+        assert_eq!(g.dump(|_, x| x), grid.rotate_ccw().dump(|_, x| x));
+
+        let mut g = grid.clone();
+        g.rotate_180_mut();
+        assert_eq!(g.dump(|_, x| x), grid.rotate_180().dump(|_, x| x));
+
+        let mut g = grid.clone();
+        g.transpose_mut();
+        assert_eq!(g.dump(|_, x| x), grid.transpose().dump(|_, x| x));
+    }
+
+    #[test]
+    fn test_reflections() {
+        let grid = Grid::raw(vec![
+            "#.##..##.".to_owned(),
+            "..#.##.#.".to_owned(),
+            "##......#".to_owned(),
+            "##......#".to_owned(),
+            "..#.##.#.".to_owned(),
+            "..##..##.".to_owned(),
+            "#.#.##.#.".to_owned(),
+        ]);
+
+        let found = grid.reflections(Axis::Vertical, 0);
+        assert_eq!(
+            found,
+            vec![Reflection {
+                axis: Axis::Vertical,
+                line: 5,
+                diff: vec![]
+            }]
+        );
+        assert!(grid.reflections(Axis::Horizontal, 0).is_empty());
+
+        let found = grid
+            .reflections(Axis::Horizontal, 1)
+            .into_iter()
+            .map(|r| (r.line, r.diff))
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![(3, vec![((0, 0), (5, 0))])]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::raw(vec!["123".to_owned(), "456".to_owned()]);
+        assert_eq!(
+            grid.neighbors(&(0, 0), Neighborhood::Four),
+            vec![((0, 1), Dir8::East), ((1, 0), Dir8::South)]
+        );
+        assert_eq!(
+            grid.neighbors(&(1, 1), Neighborhood::Eight),
+            vec![
+                ((0, 1), Dir8::North),
+                ((0, 2), Dir8::NorthEast),
+                ((1, 2), Dir8::East),
+                ((1, 0), Dir8::West),
+                ((0, 0), Dir8::NorthWest),
+            ]
+        );
+        for pos in [(0, 0), (0, 1), (1, 2)] {
+            let mut adj = grid
+                .neighbors(&pos, Neighborhood::Eight)
+                .into_iter()
+                .map(|(cell, _)| cell)
+                .collect::<Vec<_>>();
+            adj.sort();
+            assert_eq!(adj, grid.adj(&pos));
+        }
+    }
+
+    #[test]
+    fn test_dir() {
+        for (c, d) in [('^', Dir::North), ('R', Dir::East), ('S', Dir::South)] {
+            assert_eq!(Dir::from_char(c), Some(d));
+        }
+        assert_eq!(Dir::from_char('x'), None);
+        assert_eq!(Dir8::from_name("sw"), Some(Dir8::SouthWest));
+        assert_eq!(Dir8::NorthWest.cw(), Dir8::North);
+        assert_eq!(Dir8::North.ccw(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthEast.inv(), Dir8::SouthWest);
+        for dir in Dir::all() {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+            assert_eq!(Dir8::from(dir).rot(2).as_dir(), Some(dir.cw()));
+        }
+    }
+
+    #[test]
+    fn test_step_ray() {
+        let grid = Grid::raw(vec![
+            "1234".to_owned(),
+            "5#78".to_owned(),
+            "9ABC".to_owned(),
+        ]);
+        assert_eq!(grid.step(&(0, 0), Dir::East, 3), Some((0, 3)));
+        assert_eq!(grid.step(&(0, 0), Dir::East, 4), None);
+        assert_eq!(grid.step(&(2, 0), Dir8::NorthEast, 2), Some((0, 2)));
+        assert_eq!(grid.step(&(2, 0), Dir8::NorthEast, 3), None);
+        assert_eq!(grid.step(&(1, 1), Dir::East, usize::MAX), None);
+        assert_eq!(grid.step(&(1, 1), Dir::West, usize::MAX), None);
+        assert_eq!(grid.step(&(1, 1), Dir::East, isize::MAX as usize), None);
+
+        let ray = grid.ray(&(2, 1), Dir::North).collect::<Vec<_>>();
+        assert_eq!(ray, vec![(1, 1), (0, 1)]);
+        let ray = grid.ray(&(0, 3), Dir::East).collect::<Vec<_>>();
+        assert!(ray.is_empty());
+
+        let ray = grid
+            .ray_until(&(2, 1), Dir::North, |c| c == &'#')
+            .collect::<Vec<_>>();
+        assert_eq!(ray, vec![(1, 1)]);
+        let ray = grid
+            .ray_until(&(1, 0), Dir::East, |c| c == &'X')
+            .collect::<Vec<_>>();
+        assert_eq!(ray, vec![(1, 1), (1, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn test_line() {
+        let grid = Grid::raw(vec![
+            "1234".to_owned(),
+            "5#78".to_owned(),
+            "9ABC".to_owned(),
+        ]);
+        assert_eq!(grid.line(&(0, 0), &(0, 2)), vec![(0, 0), (0, 1), (0, 2)]);
+        assert_eq!(grid.line(&(2, 2), &(0, 0)), vec![(2, 2), (1, 1), (0, 0)]);
+        assert_eq!(
+            grid.line(&(0, 0), &(2, 3)),
+            vec![(0, 0), (1, 1), (1, 2), (2, 3)]
+        );
+        assert_eq!(grid.line(&(1, 1), &(1, 1)), vec![(1, 1)]);
+
+        let wall = |c: &char| c == &'#';
+        assert!(!grid.visible(&(0, 0), &(2, 2), wall));
+        assert!(grid.visible(&(0, 0), &(1, 1), wall));
+        assert!(grid.visible(&(0, 0), &(2, 0), wall));
+        assert!(!grid.visible(&(0, 0), &(0, 4), wall));
+        assert!(!grid.visible(&(5, 5), &(0, 0), wall));
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = Grid::raw(vec!["12".to_owned(), "34".to_owned()]);
+        let tiled = grid.tiled();
+        assert_eq!(tiled.get(&(0, 0)), Some(&'1'));
+        assert_eq!(tiled.get(&(-1, -1)), Some(&'4'));
+        assert_eq!(tiled.get(&(5, 2)), Some(&'3'));
+        assert_eq!(tiled.tile(&(-1, 5)), Some((-1, 2)));
+        assert_eq!(tiled.wrap(&(-1, 5)), Some((1, 1)));
+        assert_eq!(tiled.next(&(0, 0), Dir::North), Some((-1, 0)));
+        assert_eq!(
+            tiled.neighbors(&(0, 0), Neighborhood::Four),
+            vec![
+                ((-1, 0), Dir8::North),
+                ((0, 1), Dir8::East),
+                ((1, 0), Dir8::South),
+                ((0, -1), Dir8::West),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_torus() {
+        let grid = Grid::raw(vec!["123".to_owned(), "456".to_owned()]);
+        let torus = grid.torus();
+        assert_eq!(torus.get(&(-1, 3)), Some(&'4'));
+        assert_eq!(torus.next(&(0, 0), Dir::North), Some((1, 0)));
+        assert_eq!(torus.next(&(0, 2), Dir::East), Some((0, 0)));
+        assert_eq!(torus.step(&(0, 0), Dir::West, 5), Some((0, 1)));
+        assert_eq!(torus.neighbors(&(0, 0), Neighborhood::Eight).len(), 8);
+        assert_eq!(torus.step(&(0, 0), Dir::East, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_empty_views() {
+        for size in [(0, 0), (0, 3), (3, 0)] {
+            let grid = Grid::filled(size, '.');
+            let tiled = grid.tiled();
+            assert_eq!(tiled.get(&(0, 0)), None);
+            assert_eq!(tiled.wrap(&(1, -1)), None);
+            assert_eq!(tiled.tile(&(1, -1)), None);
+            let torus = grid.torus();
+            assert_eq!(torus.get(&(0, 0)), None);
+            assert_eq!(torus.next(&(0, 0), Dir::East), None);
+            assert!(torus.neighbors(&(0, 0), Neighborhood::Four).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::raw(vec![
+            "..#..".to_owned(),
+            "..#..".to_owned(),
+            "###..".to_owned(),
+            "...##".to_owned(),
+        ]);
+        let open = |c: &char| c == &'.';
+        assert_eq!(grid.flood_fill(&(0, 0), Neighborhood::Four, open).len(), 4);
+        assert_eq!(grid.flood_fill(&(0, 3), Neighborhood::Four, open).len(), 6);
+        assert_eq!(
+            grid.flood_fill(&(0, 3), Neighborhood::Eight, open).len(),
+            9
+        );
+        assert!(grid
+            .flood_fill(&(0, 2), Neighborhood::Four, open)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::raw(vec![
+            "AAAA".to_owned(),
+            "BBCD".to_owned(),
+            "BBCC".to_owned(),
+            "EEEC".to_owned(),
+        ]);
+        let (labels, count) =
+            grid.label_regions(Neighborhood::Four, |a, b| a == b);
+        assert_eq!(count, 5);
+        assert_eq!(labels.get(&(1, 0)), labels.get(&(2, 1)));
+        assert_ne!(labels.get(&(1, 2)), labels.get(&(1, 3)));
+
+        let stats = grid
+            .regions(Neighborhood::Four, |a, b| a == b)
+            .iter()
+            .map(|region| (region.len(), perimeter(region), sides(region)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stats,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+
+        let grid = Grid::raw(vec![
+            "OOOOO".to_owned(),
+            "OXOXO".to_owned(),
+            "OOOOO".to_owned(),
+            "OXOXO".to_owned(),
+            "OOOOO".to_owned(),
+        ]);
+        let regions = grid.regions(Neighborhood::Four, |a, b| a == b);
+        assert_eq!(regions.len(), 5);
+        assert_eq!(perimeter(&regions[0]), 36);
+        assert_eq!(sides(&regions[0]), 20);
+    }
+
+    #[test]
+    fn test_interior_degenerate() {
+        for path in [vec![], vec![(3, 3)], vec![(3, 3), (3, 4)]] {
+            assert_eq!(interior(&path).len(), 0);
+            assert_eq!(interior_count(&path), interior(&path).len());
+        }
+    }
+
+    #[test]
+    fn test_interior() {
+        // ...........
+        // .S-------7.
+        // .|F-----7|.
+        // .||.....||.
+        // .||.....||.
+        // .|L-7.F-J|.
+        // .|..|.|..|.
+        // .L--J.L--J.
+        // ...........
+        let mut path: Vec<Pos> = Vec::new();
+        let mut pos = (1, 1);
+        for (dir, len) in "R8 D6 L3 U2 R2 U3 L6 D3 R2 D2 L3 U6"
+            .split_whitespace()
+            .map(|s| (s.chars().next().unwrap(), s[1..].parse().unwrap()))
+        {
+            let (drow, dcol) = Dir::from_char(dir).unwrap().delta();
+            for _ in 0..len {
+                path.push(pos);
+                pos = (pos.0 + drow, pos.1 + dcol);
+            }
+        }
+        assert_eq!(pos, (1, 1));
+        assert_eq!(path.len(), 46);
+
+        let cells = interior(&path);
+        assert_eq!(cells.len(), 4);
+        assert!(cells.contains(&(6, 2)));
+        assert!(!cells.contains(&(6, 5)));
+        assert_eq!(interior_count(&path), 4);
+
+        let square = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0)];
+        let square = [&square[..], &[(1, 0)]].concat();
+        assert_eq!(interior(&square), Set::from([(1, 1)]));
+        assert_eq!(interior_count(&square), 1);
+    }
+
+    #[test]
+    fn test_compact() {
+        let grid = Grid::raw(vec![
+            "O.#.O".to_owned(),
+            ".O..#".to_owned(),
+            "O.O.O".to_owned(),
+        ]);
+        let rock = |c: &char| c == &'O';
+        let empty = |c: &char| c == &'.';
+
+        let mut g = grid.clone();
+        assert_eq!(g.compact(Dir::West, rock, empty), 4);
+        assert_eq!(g.dump(|_, x| x), "O.#O.\nO...#\nOOO..");
+
+        let mut g = grid.clone();
+        assert_eq!(g.compact(Dir::East, rock, empty), 4);
+        assert_eq!(g.dump(|_, x| x), ".O#.O\n...O#\n..OOO");
+
+        let mut g = grid.clone();
+        assert_eq!(g.compact(Dir::North, rock, empty), 3);
+        assert_eq!(g.dump(|_, x| x), "OO#.O\nO.O.#\n....O");
+
+        let mut g = grid.clone();
+        assert_eq!(g.compact(Dir::South, rock, empty), 2);
+        assert_eq!(g.dump(|_, x| x), "..#.O\nO...#\nOOO.O");
+    }
+
+    #[test]
+    fn test_try_new() {
+        let digit = |c: char| c.to_digit(10).ok_or("not a digit");
+
+        let grid = Grid::try_new(vec!["12".to_owned(), "34".to_owned()], digit);
+        assert_eq!(grid.map(|g| g.size()), Ok((2, 2)));
+
+        let grid = Grid::try_new(vec!["12".to_owned(), "3x".to_owned()], digit);
+        assert_eq!(
+            grid.unwrap_err(),
+            GridError::Cell {
+                row: 1,
+                col: 1,
+                chr: 'x',
+                err: "not a digit"
+            }
+        );
+
+        let grid = Grid::try_new(vec!["12".to_owned(), "3".to_owned()], digit);
+        assert_eq!(
+            grid.unwrap_err(),
+            GridError::Ragged {
+                row: 1,
+                expected: 2,
+                actual: 1
+            }
+        );
+
+        let grid = Grid::try_new(vec![], digit);
+        assert_eq!(grid.unwrap_err(), GridError::Empty);
+
+        let grid = Grid::try_new(vec!["".into()], digit);
+        assert_eq!(grid.unwrap_err(), GridError::Empty);
+
+        let grid = Grid::try_new(vec!["äö".into()], Ok::<char, ()>);
+        assert_eq!(grid.map(|g| g.size()), Ok((1, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn test_new_empty_line() {
+        Grid::raw(vec!["".into()]);
+    }
+
+    #[test]
+    fn test_try_padded() {
+        let grid = Grid::try_padded(
+            vec!["#".to_owned(), "###".to_owned(), "".to_owned()],
+            '.',
+            Ok::<char, ()>,
+        )
+        .unwrap();
+        assert_eq!(grid.size(), (3, 3));
+        assert_eq!(grid.dump(|_, x| x), "#..\n###\n...");
+    }
+
     // TODO add tests
 }
@@ -0,0 +1,503 @@
+use std::collections::{HashMap as Map, HashSet as Set, VecDeque as Seq};
+use std::fmt::Debug;
+
+/// Type alias for a 3D cell position (x, y, z) in a dense grid
+pub type Cell3 = (usize, usize, usize);
+
+/// Type alias for a signed 3D position (x, y, z) in unbounded space
+pub type Pos3 = (isize, isize, isize);
+
+/// Coordinate axis in 3D space
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis3 {
+    // Get coordinate of a position along the axis.
+    fn of(&self, pos: &Pos3) -> isize {
+        match self {
+            Axis3::X => pos.0,
+            Axis3::Y => pos.1,
+            Axis3::Z => pos.2,
+        }
+    }
+
+    // Get position with coordinate along the axis replaced.
+    fn with(&self, pos: &Pos3, val: isize) -> Pos3 {
+        match self {
+            Axis3::X => (val, pos.1, pos.2),
+            Axis3::Y => (pos.0, val, pos.2),
+            Axis3::Z => (pos.0, pos.1, val),
+        }
+    }
+}
+
+/// Set of neighbors considered adjacent to a 3D cell
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Neighborhood3 {
+    /// Cells sharing a face
+    Six,
+    /// Cells sharing a face, an edge or a corner
+    TwentySix,
+}
+
+impl Neighborhood3 {
+    /// Get (x, y, z) offsets that make up the neighborhood
+    pub fn deltas(&self) -> Vec<Pos3> {
+        match self {
+            Neighborhood3::Six => vec![
+                (-1, 0, 0),
+                (1, 0, 0),
+                (0, -1, 0),
+                (0, 1, 0),
+                (0, 0, -1),
+                (0, 0, 1),
+            ],
+            Neighborhood3::TwentySix => {
+                let mut ret = Vec::with_capacity(26);
+                for dx in [-1, 0, 1] {
+                    for dy in [-1, 0, 1] {
+                        for dz in [-1, 0, 1] {
+                            if (dx, dy, dz) != (0, 0, 0) {
+                                ret.push((dx, dy, dz));
+                            }
+                        }
+                    }
+                }
+                ret
+            }
+        }
+    }
+}
+
+/// Generic dense 3D grid implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid3<T: Clone + Debug + 'static> {
+    size: Cell3,
+    data: Vec<T>,
+}
+
+impl<T: Clone + Debug + 'static> Grid3<T> {
+    /// Create a new grid of a given size (x, y, z) filled with a value
+    pub fn filled(size: Cell3, val: T) -> Self {
+        Self {
+            size,
+            data: vec![val; size.0 * size.1 * size.2],
+        }
+    }
+
+    /// Get grid size (x, y, z)
+    pub fn size(&self) -> Cell3 {
+        self.size
+    }
+
+    fn index(&self, pos: &Cell3) -> Option<usize> {
+        let (x, y, z) = *pos;
+        let (xs, ys, zs) = self.size;
+        (x < xs && y < ys && z < zs).then(|| (z * ys + y) * xs + x)
+    }
+
+    /// Get grid item at a given position
+    pub fn get(&self, pos: &Cell3) -> Option<&T> {
+        self.index(pos).map(|idx| &self.data[idx])
+    }
+
+    /// Get mutable reference to a grid item at a given position
+    pub fn get_mut(&mut self, pos: &Cell3) -> Option<&mut T> {
+        self.index(pos).map(|idx| &mut self.data[idx])
+    }
+
+    /// Set grid item at a given position
+    pub fn set(&mut self, pos: &Cell3, val: T) {
+        *self.get_mut(pos).unwrap() = val;
+    }
+
+    /// Get adjacent positions to a given one (within grid bounds)
+    pub fn neighbors(&self, pos: &Cell3, hood: Neighborhood3) -> Vec<Cell3> {
+        hood.deltas()
+            .into_iter()
+            .filter_map(|(dx, dy, dz)| {
+                let next = (
+                    pos.0.checked_add_signed(dx)?,
+                    pos.1.checked_add_signed(dy)?,
+                    pos.2.checked_add_signed(dz)?,
+                );
+                self.index(&next).map(|_| next)
+            })
+            .collect()
+    }
+
+    /// Find positions of grid cells that match a predicate
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Vec<Cell3> {
+        self.iter()
+            .filter(|(_, val)| f(val))
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Iterate over grid cells by z, then by y, then by x
+    pub fn iter(&self) -> impl Iterator<Item = (Cell3, &T)> {
+        let (xs, ys, _) = self.size;
+        self.data.iter().enumerate().map(move |(idx, val)| {
+            ((idx % xs, (idx / xs) % ys, idx / (xs * ys)), val)
+        })
+    }
+}
+
+/// Sparse unbounded 3D grid: cells that were never set hold a default value
+#[derive(Clone, Debug)]
+pub struct Mesh3<T: Clone + Debug + PartialEq + 'static> {
+    data: Map<Pos3, T>,
+    default: T,
+}
+
+impl<T: Clone + Debug + PartialEq + 'static> Mesh3<T> {
+    /// Create a new empty mesh
+    pub fn new(default: T) -> Self {
+        Self {
+            data: Map::new(),
+            default,
+        }
+    }
+
+    /// Get number of cells holding non-default values
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if all cells hold the default value
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get mesh item at a given position
+    pub fn get(&self, pos: &Pos3) -> &T {
+        self.data.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Set mesh item at a given position
+    pub fn set(&mut self, pos: &Pos3, val: T) {
+        if val == self.default {
+            self.data.remove(pos);
+        } else {
+            self.data.insert(*pos, val);
+        }
+    }
+
+    /// Get adjacent positions to a given one
+    pub fn neighbors(&self, pos: &Pos3, hood: Neighborhood3) -> Vec<Pos3> {
+        hood.deltas()
+            .into_iter()
+            .map(|(dx, dy, dz)| (pos.0 + dx, pos.1 + dy, pos.2 + dz))
+            .collect()
+    }
+
+    /// Iterate over non-default cells (in arbitrary order)
+    pub fn iter(&self) -> impl Iterator<Item = (&Pos3, &T)> {
+        self.data.iter()
+    }
+
+    /// Get bounding box of non-default cells
+    pub fn bounds(&self) -> Option<Cuboid> {
+        Cuboid::bounding(self.data.keys())
+    }
+}
+
+/// Axis-aligned box given by its min and max corners (inclusive)
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Cuboid {
+    pub min: Pos3,
+    pub max: Pos3,
+}
+
+impl Cuboid {
+    /// Create a new box out of two opposite corners (in any order)
+    pub fn new(a: Pos3, b: Pos3) -> Self {
+        Self {
+            min: (a.0.min(b.0), a.1.min(b.1), a.2.min(b.2)),
+            max: (a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)),
+        }
+    }
+
+    /// Get the smallest box containing all given positions
+    pub fn bounding<'a>(
+        cells: impl IntoIterator<Item = &'a Pos3>,
+    ) -> Option<Self> {
+        let mut it = cells.into_iter();
+        let first = *it.next()?;
+        Some(it.fold(Self::new(first, first), |acc, pos| {
+            Self::new(
+                (
+                    acc.min.0.min(pos.0),
+                    acc.min.1.min(pos.1),
+                    acc.min.2.min(pos.2),
+                ),
+                (
+                    acc.max.0.max(pos.0),
+                    acc.max.1.max(pos.1),
+                    acc.max.2.max(pos.2),
+                ),
+            )
+        }))
+    }
+
+    /// Get number of cells in the box
+    pub fn volume(&self) -> usize {
+        ((self.max.0 - self.min.0 + 1)
+            * (self.max.1 - self.min.1 + 1)
+            * (self.max.2 - self.min.2 + 1)) as usize
+    }
+
+    /// Check if the box contains a given position
+    pub fn contains(&self, pos: &Pos3) -> bool {
+        (self.min.0..=self.max.0).contains(&pos.0)
+            && (self.min.1..=self.max.1).contains(&pos.1)
+            && (self.min.2..=self.max.2).contains(&pos.2)
+    }
+
+    /// Check if two boxes share at least one cell
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Get box of cells shared by two boxes
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = (
+            self.min.0.max(other.min.0),
+            self.min.1.max(other.min.1),
+            self.min.2.max(other.min.2),
+        );
+        let max = (
+            self.max.0.min(other.max.0),
+            self.max.1.min(other.max.1),
+            self.max.2.min(other.max.2),
+        );
+        (min.0 <= max.0 && min.1 <= max.1 && min.2 <= max.2)
+            .then_some(Cuboid { min, max })
+    }
+
+    /// Get the box moved by a given offset
+    pub fn shift(&self, delta: Pos3) -> Cuboid {
+        let add = |p: Pos3| (p.0 + delta.0, p.1 + delta.1, p.2 + delta.2);
+        Cuboid {
+            min: add(self.min),
+            max: add(self.max),
+        }
+    }
+
+    /// Get the box grown by `n` cells in every direction
+    pub fn expand(&self, n: isize) -> Cuboid {
+        Cuboid {
+            min: (self.min.0 - n, self.min.1 - n, self.min.2 - n),
+            max: (self.max.0 + n, self.max.1 + n, self.max.2 + n),
+        }
+    }
+
+    /// Iterate over all cells in the box
+    pub fn cells(&self) -> impl Iterator<Item = Pos3> + '_ {
+        (self.min.2..=self.max.2).flat_map(move |z| {
+            (self.min.1..=self.max.1).flat_map(move |y| {
+                (self.min.0..=self.max.0).map(move |x| (x, y, z))
+            })
+        })
+    }
+}
+
+/// Count cell faces not shared with another cell of the set
+pub fn surface_area(cells: &Set<Pos3>) -> usize {
+    cells
+        .iter()
+        .flat_map(adjacent)
+        .filter(|pos| !cells.contains(pos))
+        .count()
+}
+
+/// Count cell faces reachable from outside (enclosed air pockets excluded)
+pub fn exterior_surface_area(cells: &Set<Pos3>) -> usize {
+    let Some(bounds) = Cuboid::bounding(cells) else {
+        return 0;
+    };
+    let bounds = bounds.expand(1);
+    let mut ret = 0;
+    let mut seen: Set<Pos3> = Set::new();
+    let mut queue: Seq<Pos3> = Seq::new();
+    seen.insert(bounds.min);
+    queue.push_back(bounds.min);
+    while let Some(pos) = queue.pop_front() {
+        for next in adjacent(&pos) {
+            if cells.contains(&next) {
+                ret += 1;
+            } else if bounds.contains(&next) && seen.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    ret
+}
+
+fn adjacent(pos: &Pos3) -> impl Iterator<Item = Pos3> + '_ {
+    Neighborhood3::Six
+        .deltas()
+        .into_iter()
+        .map(|(dx, dy, dz)| (pos.0 + dx, pos.1 + dy, pos.2 + dz))
+}
+
+/// Boxes after settling, with support relationships between them
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settled {
+    /// Boxes at their final positions (same order as given)
+    pub boxes: Vec<Cuboid>,
+    /// Indices of boxes resting directly on top of each box
+    pub supports: Vec<Vec<usize>>,
+    /// Indices of boxes each box rests directly on
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Settled {
+    /// Check if a box can be removed without any other box falling
+    pub fn is_removable(&self, idx: usize) -> bool {
+        self.supports[idx]
+            .iter()
+            .all(|top| self.supported_by[*top].len() > 1)
+    }
+}
+
+/// Drop boxes along an axis towards lower coordinates until each one rests
+/// either on the floor (lowest allowed coordinate) or on another box
+pub fn settle(boxes: &[Cuboid], axis: Axis3, floor: isize) -> Settled {
+    let n = boxes.len();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by_key(|idx| axis.of(&boxes[*idx].min));
+
+    // top coordinate and box index for each footprint cell
+    let mut tops: Map<Pos3, (isize, usize)> = Map::new();
+    let mut ret = Settled {
+        boxes: boxes.to_vec(),
+        supports: vec![Vec::new(); n],
+        supported_by: vec![Vec::new(); n],
+    };
+    for idx in order {
+        let cuboid = boxes[idx];
+        let footprint =
+            Cuboid::new(axis.with(&cuboid.min, 0), axis.with(&cuboid.max, 0));
+        let below = footprint
+            .cells()
+            .filter_map(|cell| tops.get(&cell).cloned())
+            .collect::<Vec<_>>();
+        let rest = below
+            .iter()
+            .map(|(top, _)| top + 1)
+            .max()
+            .unwrap_or(floor)
+            .max(floor);
+        let mut under = below
+            .into_iter()
+            .filter(|(top, _)| top + 1 == rest)
+            .map(|(_, idx)| idx)
+            .collect::<Vec<_>>();
+        under.sort();
+        under.dedup();
+        for other in &under {
+            ret.supports[*other].push(idx);
+        }
+        ret.supported_by[idx] = under;
+
+        let drop = axis.of(&cuboid.min) - rest;
+        let delta = axis.with(&(0, 0, 0), -drop);
+        let cuboid = cuboid.shift(delta);
+        ret.boxes[idx] = cuboid;
+        for cell in footprint.cells() {
+            tops.insert(cell, (axis.of(&cuboid.max), idx));
+        }
+    }
+    ret.supports.iter_mut().for_each(|s| s.sort());
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid3() {
+        let mut grid = Grid3::filled((2, 3, 4), 0);
+        grid.set(&(1, 2, 3), 7);
+        assert_eq!(grid.get(&(1, 2, 3)), Some(&7));
+        assert_eq!(grid.get(&(2, 0, 0)), None);
+        assert_eq!(grid.find(|x| *x == 7), vec![(1, 2, 3)]);
+        assert_eq!(grid.iter().count(), 24);
+        assert_eq!(grid.neighbors(&(0, 0, 0), Neighborhood3::Six).len(), 3);
+        assert_eq!(
+            grid.neighbors(&(1, 1, 1), Neighborhood3::TwentySix).len(),
+            17
+        );
+        assert_eq!(Neighborhood3::TwentySix.deltas().len(), 26);
+
+        let mut mesh = Mesh3::new(false);
+        mesh.set(&(-1, 0, 5), true);
+        mesh.set(&(2, 3, 1), true);
+        assert_eq!(mesh.len(), 2);
+        assert_eq!(mesh.bounds(), Some(Cuboid::new((-1, 0, 1), (2, 3, 5))));
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::new((0, 0, 0), (2, 2, 2));
+        let b = Cuboid::new((3, 3, 3), (1, 1, 1));
+        let c = Cuboid::new((3, 0, 0), (4, 0, 0));
+        assert_eq!(a.volume(), 27);
+        assert_eq!(a.intersection(&b), Some(Cuboid::new((1, 1, 1), (2, 2, 2))));
+        assert!(!a.intersects(&c));
+        assert!(b.intersects(&c.shift((0, 1, 1))));
+        assert_eq!(c.cells().collect::<Vec<_>>(), vec![(3, 0, 0), (4, 0, 0)]);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        let cells = [
+            (2, 2, 2),
+            (1, 2, 2),
+            (3, 2, 2),
+            (2, 1, 2),
+            (2, 3, 2),
+            (2, 2, 1),
+            (2, 2, 3),
+            (2, 2, 4),
+            (2, 2, 6),
+            (1, 2, 5),
+            (3, 2, 5),
+            (2, 1, 5),
+            (2, 3, 5),
+        ]
+        .into_iter()
+        .collect::<Set<_>>();
+        assert_eq!(surface_area(&cells), 64);
+        assert_eq!(exterior_surface_area(&cells), 58);
+    }
+
+    #[test]
+    fn test_settle() {
+        let boxes = [
+            ((1, 0, 1), (1, 2, 1)),
+            ((0, 0, 2), (2, 0, 2)),
+            ((0, 2, 3), (2, 2, 3)),
+            ((0, 0, 4), (0, 2, 4)),
+            ((2, 0, 5), (2, 2, 5)),
+            ((0, 1, 6), (2, 1, 6)),
+            ((1, 1, 8), (1, 1, 9)),
+        ]
+        .map(|(a, b)| Cuboid::new(a, b));
+        let settled = settle(&boxes, Axis3::Z, 1);
+        assert_eq!(settled.boxes[6], Cuboid::new((1, 1, 5), (1, 1, 6)));
+        assert_eq!(settled.supports[0], vec![1, 2]);
+        assert_eq!(settled.supported_by[3], vec![1, 2]);
+        assert_eq!(settled.supported_by[0], vec![]);
+        let removable = (0..boxes.len())
+            .filter(|idx| settled.is_removable(*idx))
+            .count();
+        assert_eq!(removable, 5);
+    }
+}
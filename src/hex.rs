@@ -0,0 +1,245 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::grid::{Dir8, Pos};
+
+/// Hexagonal cell in axial coordinates (cube coordinate `s = -q - r`)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// Orientation of hexagons, which defines the names of the six directions
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Layout {
+    /// Pointy side up: directions are E, NE, NW, W, SW, SE
+    Pointy,
+    /// Flat side up: directions are N, NE, SE, S, SW, NW
+    Flat,
+}
+
+// Axial offsets of the six neighbors, counter-clockwise.
+const DELTAS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+impl Layout {
+    /// Get the six directions in the same order as `Hex::neighbors`
+    pub fn dirs(&self) -> [Dir8; 6] {
+        match self {
+            Layout::Pointy => [
+                Dir8::East,
+                Dir8::NorthEast,
+                Dir8::NorthWest,
+                Dir8::West,
+                Dir8::SouthWest,
+                Dir8::SouthEast,
+            ],
+            Layout::Flat => [
+                Dir8::SouthEast,
+                Dir8::NorthEast,
+                Dir8::North,
+                Dir8::NorthWest,
+                Dir8::SouthWest,
+                Dir8::South,
+            ],
+        }
+    }
+
+    /// Get axial offset of a single step in a given direction (if the
+    /// direction exists in this layout)
+    pub fn delta(&self, dir: Dir8) -> Option<Hex> {
+        self.dirs()
+            .iter()
+            .position(|d| *d == dir)
+            .map(|idx| DELTAS[idx])
+    }
+
+    /// Parse directions separated by commas/whitespace (`ne,sw,nw`) or
+    /// concatenated without separators (`esenee`)
+    pub fn parse(&self, s: &str) -> Option<Vec<Dir8>> {
+        let mut ret = Vec::new();
+        for chunk in s.split(|c: char| c == ',' || c.is_whitespace()) {
+            let chunk = chunk.to_ascii_uppercase();
+            let mut rest = chunk.as_str();
+            while !rest.is_empty() {
+                let dir = [2, 1].into_iter().find_map(|len| {
+                    let name = rest.get(..len)?;
+                    let dir = Dir8::from_name(name)?;
+                    self.delta(dir).map(|_| (dir, len))
+                });
+                let (dir, len) = dir?;
+                ret.push(dir);
+                rest = &rest[len..];
+            }
+        }
+        Some(ret)
+    }
+
+    /// Follow directions from a given hex
+    pub fn walk(&self, from: Hex, dirs: &[Dir8]) -> Option<Hex> {
+        dirs.iter()
+            .try_fold(from, |at, dir| self.delta(*dir).map(|d| at + d))
+    }
+}
+
+impl Hex {
+    /// Create a new hex out of axial coordinates
+    pub const fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    /// Get the third cube coordinate
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    /// Get number of steps between two hexes
+    pub fn distance(&self, other: &Hex) -> usize {
+        let d = *self - *other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s().unsigned_abs()) / 2
+    }
+
+    /// Get the six adjacent hexes (counter-clockwise, see `Layout::dirs`)
+    pub fn neighbors(&self) -> [Hex; 6] {
+        DELTAS.map(|d| *self + d)
+    }
+
+    /// Get hexes exactly `radius` steps away, going around the ring
+    pub fn ring(&self, radius: usize) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let mut ret = Vec::with_capacity(6 * radius);
+        let mut at = *self + DELTAS[4] * radius as isize;
+        for d in DELTAS {
+            for _ in 0..radius {
+                ret.push(at);
+                at = at + d;
+            }
+        }
+        ret
+    }
+
+    /// Get hexes at most `radius` steps away, ring by ring from the center
+    pub fn spiral(&self, radius: usize) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Get axial coordinates as a position usable with `Mesh` and `Automaton`
+    pub fn pos(&self) -> Pos {
+        (self.q, self.r)
+    }
+}
+
+impl From<Pos> for Hex {
+    fn from(pos: Pos) -> Self {
+        Hex::new(pos.0, pos.1)
+    }
+}
+
+/// Get axial offsets of the six neighbors (for `Automaton::with_deltas`)
+pub fn deltas() -> Vec<Pos> {
+    DELTAS.iter().map(Hex::pos).collect()
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<isize> for Hex {
+    type Output = Hex;
+
+    fn mul(self, k: isize) -> Hex {
+        Hex::new(self.q * k, self.r * k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automaton::Automaton;
+    use crate::mesh::Mesh;
+
+    #[test]
+    fn test_flat() {
+        let origin = Hex::default();
+        for (path, dist) in [
+            ("ne,ne,ne", 3),
+            ("ne,ne,sw,sw", 0),
+            ("ne,ne,s,s", 2),
+            ("se,sw,se,sw,sw", 3),
+        ] {
+            let dirs = Layout::Flat.parse(path).unwrap();
+            let end = Layout::Flat.walk(origin, &dirs).unwrap();
+            assert_eq!(end.distance(&origin), dist);
+        }
+        assert_eq!(Layout::Flat.parse("ne,e"), None);
+    }
+
+    #[test]
+    fn test_pointy() {
+        let origin = Hex::default();
+        let dirs = Layout::Pointy.parse("nwwswee").unwrap();
+        assert_eq!(dirs.len(), 5);
+        assert_eq!(Layout::Pointy.walk(origin, &dirs), Some(origin));
+
+        let dirs = Layout::Pointy.parse("esew").unwrap();
+        let end = Layout::Pointy.walk(origin, &dirs).unwrap();
+        assert_eq!(Some(end - origin), Layout::Pointy.delta(Dir8::SouthEast));
+        assert_eq!(Layout::Pointy.parse("n"), None);
+    }
+
+    #[test]
+    fn test_ring_spiral() {
+        let center = Hex::new(2, -1);
+        assert_eq!(center.ring(0), vec![center]);
+        for radius in 1..4 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|h| h.distance(&center) == radius));
+            assert_eq!(ring[1].distance(&ring[0]), 1);
+        }
+        assert_eq!(center.spiral(2).len(), 19);
+        let mut ring = center.ring(1);
+        ring.sort();
+        let mut adj = center.neighbors().to_vec();
+        adj.sort();
+        assert_eq!(ring, adj);
+    }
+
+    #[test]
+    fn test_automaton() {
+        let flip = |black: &bool, adj: &[&bool]| {
+            let n = adj.iter().filter(|b| ***b).count();
+            matches!((black, n), (true, 1 | 2) | (false, 2))
+        };
+        let hex = Automaton::with_deltas(deltas(), flip);
+
+        let mut mesh = Mesh::new(false);
+        mesh.set(&Hex::new(0, 0).pos(), true);
+        mesh.set(&Hex::new(1, 1).pos(), true);
+
+        let mesh = hex.step_mesh(&mesh);
+        assert_eq!(mesh.find(|b| *b), vec![(0, 1), (1, 0)]);
+        let mesh = hex.step_mesh(&mesh);
+        assert_eq!(mesh.find(|b| *b), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Debug;
+use std::io;
+use std::path::Path;
+
+use crate::grid::{Cell, Grid};
+
+/// Type alias for an RGB color
+pub type Rgb = [u8; 3];
+
+/// Simple RGB raster image
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Create a new image of a given size filled with a color
+    pub fn new(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    /// Get image size (width, height)
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Get pixel color at a given position
+    pub fn get(&self, x: usize, y: usize) -> Option<&Rgb> {
+        (x < self.width && y < self.height)
+            .then(|| &self.pixels[y * self.width + x])
+    }
+
+    /// Set pixel color at a given position
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Encode image as binary PPM (P6)
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut ret =
+            format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ret.reserve(self.pixels.len() * 3);
+        self.pixels.iter().for_each(|px| ret.extend_from_slice(px));
+        ret
+    }
+
+    /// Encode image as PNG (8-bit RGB, uncompressed deflate blocks)
+    pub fn png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0); // filter: none
+            row.iter().for_each(|px| raw.extend_from_slice(px));
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(self.width as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(self.height as u32).to_be_bytes());
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut ret = b"\x89PNG\r\n\x1a\n".to_vec();
+        chunk(&mut ret, b"IHDR", &ihdr);
+        chunk(&mut ret, b"IDAT", &zlib(&raw));
+        chunk(&mut ret, b"IEND", &[]);
+        ret
+    }
+
+    /// Save image to a file, format is picked by extension (`png` or `ppm`)
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.png(),
+            Some("ppm") => self.ppm(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unsupported image format",
+                ))
+            }
+        };
+        std::fs::write(path, bytes)
+    }
+}
+
+impl<T: Clone + Debug + 'static> Grid<T> {
+    /// Render the grid as an image, each cell becomes a `scale`x`scale`
+    /// square colored by the palette
+    pub fn image(
+        &self,
+        scale: usize,
+        palette: impl Fn(&Cell, &T) -> Rgb,
+    ) -> Image {
+        let (rows, cols) = self.size();
+        let mut ret = Image::new(cols * scale, rows * scale, [0, 0, 0]);
+        for (row, col, val) in self.iter() {
+            let color = palette(&(row, col), val);
+            for y in row * scale..(row + 1) * scale {
+                for x in col * scale..(col + 1) * scale {
+                    ret.set(x, y, color);
+                }
+            }
+        }
+        ret
+    }
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+// Wrap data into a zlib stream made of stored (uncompressed) deflate blocks.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    const MAX: usize = u16::MAX as usize;
+    let mut ret = vec![0x78, 0x01];
+    let blocks = data.len().div_ceil(MAX).max(1);
+    for i in 0..blocks {
+        let block = &data[i * MAX..((i + 1) * MAX).min(data.len())];
+        let len = block.len() as u16;
+        ret.push((i == blocks - 1) as u8);
+        ret.extend_from_slice(&len.to_le_bytes());
+        ret.extend_from_slice(&(!len).to_le_bytes());
+        ret.extend_from_slice(block);
+    }
+    ret.extend_from_slice(&adler32(data).to_be_bytes());
+    ret
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+        assert_eq!(crc32(b"IEND"), 0xAE426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E60398);
+    }
+
+    #[test]
+    fn test_image() {
+        let grid = Grid::raw(vec!["#.".to_owned(), ".#".to_owned()]);
+        let image = grid.image(2, |_, c| {
+            if c == &'#' {
+                [255, 255, 255]
+            } else {
+                [0, 0, 0]
+            }
+        });
+        assert_eq!(image.size(), (4, 4));
+        assert_eq!(image.get(1, 1), Some(&[255, 255, 255]));
+        assert_eq!(image.get(2, 1), Some(&[0, 0, 0]));
+        assert_eq!(image.get(3, 3), Some(&[255, 255, 255]));
+        assert_eq!(image.get(4, 0), None);
+
+        let ppm = image.ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let png = image.png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert!(png.ends_with(
+            &[0, 0, 0, 0, b'I', b'E', b'N', b'D']
+                .iter()
+                .chain(&0xAE426082u32.to_be_bytes())
+                .cloned()
+                .collect::<Vec<_>>()
+        ));
+        // header + IHDR + IDAT (zlib: 2 + 5 + 4 * 13 + 4) + IEND
+        assert_eq!(png.len(), 8 + 25 + 12 + 63 + 12);
+    }
+}
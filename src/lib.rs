@@ -1,19 +1,23 @@
 /*
-use advent_of_code_2023::*;
+// src/days/day00.rs (register in `days::ALL`)
+use crate::solution::Solution;
 
-fn main() {
-    let _ = lines();
+pub struct Day00;
 
-    // println!("{}", part1()); // .
-    // println!("{}", part2()); // .
-}
+impl Solution for Day00 {
+    type Input = Vec<String>;
+
+    fn parse(lines: Vec<String>) -> Self::Input {
+        lines
+    }
 
-// cargo run --bin day00 < txt/day00.txt
-// cargo run --release --bin day00 < txt/day00.txt
-// cargo test --package advent-of-code-2023 --bin day00 -- day00 --nocapture
+    fn part1(_input: &Self::Input) -> String {
+        todo!()
+    }
+}
 
 #[cfg(test)]
-mod day00 {
+mod tests {
     use super::*;
 
     #[test]
@@ -21,15 +25,45 @@ mod day00 {
         //
     }
 }
+
+// src/bin/day00.rs
+use advent_of_code_2023::days::day00::Day00;
+use advent_of_code_2023::solution;
+
+fn main() {
+    solution::main::<Day00>();
+}
+
+// cargo run --bin aoc -- run 0
+// cargo run --release --bin aoc -- run 0
+// cargo test --package advent-of-code-2023 --lib -- days::day00 --nocapture
 */
 
+pub mod automaton;
+pub mod bitgrid;
+pub mod blocks;
+pub mod coords;
+pub mod days;
+pub mod diff;
+pub mod distance;
+pub mod geom;
 pub mod graf;
 pub mod grid;
-// TODO mesh; // sparse grid (unbounded)
+pub mod grid3;
+pub mod hex;
+pub mod image;
+pub mod mesh;
+pub mod pattern;
+pub mod solution;
+pub mod svg;
+pub mod term;
+pub mod token;
+pub mod view;
 // TODO heap (Priority Queue)
 // TODO dset (Disjoint Set: https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
 
 pub use grid::Grid;
+pub use mesh::Mesh;
 
 pub fn lines() -> Vec<String> {
     use std::io::BufRead;
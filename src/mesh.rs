@@ -0,0 +1,140 @@
+use std::collections::HashMap as Map;
+use std::fmt::Debug;
+
+use crate::grid::{Dir8, Grid, Neighborhood, Pos};
+
+/// Sparse unbounded grid: cells that were never set hold a default value
+#[derive(Clone, Debug)]
+pub struct Mesh<T: Clone + Debug + PartialEq + 'static> {
+    data: Map<Pos, T>,
+    default: T,
+}
+
+impl<T: Clone + Debug + PartialEq + 'static> Mesh<T> {
+    /// Create a new empty mesh
+    pub fn new(default: T) -> Self {
+        Self {
+            data: Map::new(),
+            default,
+        }
+    }
+
+    /// Create a new mesh out of grid cells (cells equal to default are skipped)
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut ret = Self::new(default);
+        for (row, col, val) in grid.iter() {
+            ret.set(&(row as isize, col as isize), val.clone());
+        }
+        ret
+    }
+
+    /// Get default value of the mesh cells
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Get number of cells holding non-default values
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if all cells hold the default value
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get mesh item at a given position
+    pub fn get(&self, pos: &Pos) -> &T {
+        self.data.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Set mesh item at a given position
+    pub fn set(&mut self, pos: &Pos, val: T) {
+        if val == self.default {
+            self.data.remove(pos);
+        } else {
+            self.data.insert(*pos, val);
+        }
+    }
+
+    /// Get next position given current position and direction
+    pub fn next(&self, pos: &Pos, dir: impl Into<Dir8>) -> Pos {
+        let (drow, dcol) = dir.into().delta();
+        (pos.0 + drow, pos.1 + dcol)
+    }
+
+    /// Get neighbors of a given position along with direction to each
+    pub fn neighbors(&self, pos: &Pos, hood: Neighborhood) -> Vec<(Pos, Dir8)> {
+        hood.dirs()
+            .into_iter()
+            .map(|dir| (self.next(pos, dir), dir))
+            .collect()
+    }
+
+    /// Find positions of non-default cells that match a predicate
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Vec<Pos> {
+        let mut ret = self
+            .data
+            .iter()
+            .filter(|(_, val)| f(val))
+            .map(|(pos, _)| *pos)
+            .collect::<Vec<_>>();
+        ret.sort();
+        ret
+    }
+
+    /// Iterate over non-default cells (in arbitrary order)
+    pub fn iter(&self) -> impl Iterator<Item = (&Pos, &T)> {
+        self.data.iter()
+    }
+
+    /// Get bounding box (min, max) of non-default cells
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let min = self
+            .data
+            .keys()
+            .cloned()
+            .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1)))?;
+        let max = self
+            .data
+            .keys()
+            .cloned()
+            .reduce(|a, b| (a.0.max(b.0), a.1.max(b.1)))?;
+        Some((min, max))
+    }
+
+    /// Get string representation of the bounding box of non-default cells
+    pub fn dump(&self, f: impl Fn(&Pos, &T) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        (min.0..=max.0)
+            .map(|row| {
+                (min.1..=max.1)
+                    .map(|col| f(&(row, col), self.get(&(row, col))))
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mesh() {
+        let grid = Grid::raw(vec!["#.".to_owned(), ".#".to_owned()]);
+        let mut mesh = Mesh::from_grid(&grid, '.');
+        assert_eq!(mesh.len(), 2);
+        assert_eq!(mesh.get(&(-5, 7)), &'.');
+
+        mesh.set(&(-1, 2), '#');
+        mesh.set(&(0, 0), '.');
+        assert_eq!(mesh.find(|c| c == &'#'), vec![(-1, 2), (1, 1)]);
+        assert_eq!(mesh.bounds(), Some(((-1, 1), (1, 2))));
+        assert_eq!(mesh.dump(|_, c| *c), ".#\n..\n#.");
+        assert_eq!(mesh.neighbors(&(0, 0), Neighborhood::Eight).len(), 8);
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use crate::grid::{Cell, Grid};
+
+// Bases of polynomial rolling hashes along rows and along columns.
+const ROW_BASE: u64 = 1_000_003;
+const COL_BASE: u64 = 998_244_353;
+
+impl<T: Clone + Debug + Eq + Hash + 'static> Grid<T> {
+    /// Find top-left positions of all occurrences of a smaller grid; pattern
+    /// cells equal to `wildcard` match anything (Rabin-Karp rolling hash)
+    pub fn find_pattern(
+        &self,
+        pattern: &Grid<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<Cell> {
+        let (rows, cols) = self.size();
+        let (prows, pcols) = pattern.size();
+        if prows > rows || pcols > cols {
+            return Vec::new();
+        }
+        let candidates = if wildcard.is_some() {
+            self.anchored(pattern, wildcard)
+        } else {
+            self.hashed(pattern)
+        };
+        candidates
+            .into_iter()
+            .filter(|pos| self.matches(pattern, wildcard, pos))
+            .collect()
+    }
+
+    /// Find occurrences of a pattern under all 8 rotations and reflections,
+    /// grouped by variant index (in the order of `Grid::dihedral`)
+    pub fn find_pattern_dihedral(
+        &self,
+        pattern: &Grid<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<(usize, Vec<Cell>)> {
+        pattern
+            .dihedral()
+            .enumerate()
+            .map(|(idx, variant)| (idx, self.find_pattern(&variant, wildcard)))
+            .filter(|(_, found)| !found.is_empty())
+            .collect()
+    }
+
+    /// Check if a pattern matches at a given top-left position
+    pub fn matches(
+        &self,
+        pattern: &Grid<T>,
+        wildcard: Option<&T>,
+        pos: &Cell,
+    ) -> bool {
+        pattern.iter().all(|(row, col, val)| {
+            Some(val) == wildcard
+                || self.get(&(pos.0 + row, pos.1 + col)) == Some(val)
+        })
+    }
+
+    // Candidate positions from the full 2D hash of the pattern.
+    fn hashed(&self, pattern: &Grid<T>) -> Vec<Cell> {
+        let (prows, pcols) = pattern.size();
+        let column = rolling_rows(pattern, pcols)
+            .into_iter()
+            .map(|row| row[0])
+            .collect::<Vec<_>>();
+        let target = rolling(&column, prows, COL_BASE)[0];
+        let windows = rolling_rows(self, pcols);
+        let (_, cols) = self.size();
+        let mut ret = Vec::new();
+        for col in 0..=(cols - pcols) {
+            let column = windows.iter().map(|row| row[col]).collect::<Vec<_>>();
+            for (row, hash) in
+                rolling(&column, prows, COL_BASE).into_iter().enumerate()
+            {
+                if hash == target {
+                    ret.push((row, col));
+                }
+            }
+        }
+        ret.sort();
+        ret
+    }
+
+    // Candidate positions from the longest wildcard-free run of the pattern.
+    fn anchored(&self, pattern: &Grid<T>, wildcard: Option<&T>) -> Vec<Cell> {
+        let (rows, cols) = self.size();
+        let (prows, pcols) = pattern.size();
+        let mut best = (0, 0, 0); // (len, row, col)
+        for (row, cells) in pattern.rows().enumerate() {
+            let mut run = 0;
+            for (col, val) in cells.iter().enumerate() {
+                if Some(val) == wildcard {
+                    run = 0;
+                    continue;
+                }
+                run += 1;
+                if run > best.0 {
+                    best = (run, row, col + 1 - run);
+                }
+            }
+        }
+        let (len, arow, acol) = best;
+        if len == 0 {
+            return (0..=(rows - prows))
+                .flat_map(|row| (0..=(cols - pcols)).map(move |col| (row, col)))
+                .collect();
+        }
+
+        let anchor = pattern.rows().nth(arow).unwrap()[acol..acol + len]
+            .iter()
+            .map(hash)
+            .collect::<Vec<_>>();
+        let target = rolling(&anchor, len, ROW_BASE)[0];
+        let mut ret = Vec::new();
+        for (row, cells) in self.rows().enumerate() {
+            if row < arow || row - arow + prows > rows {
+                continue;
+            }
+            let cells = cells.iter().map(hash).collect::<Vec<_>>();
+            for (col, h) in
+                rolling(&cells, len, ROW_BASE).into_iter().enumerate()
+            {
+                if h == target && col >= acol && col - acol + pcols <= cols {
+                    ret.push((row - arow, col - acol));
+                }
+            }
+        }
+        ret
+    }
+}
+
+// Hash a single cell value.
+fn hash<T: Hash>(val: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    val.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Hashes of all windows of a given width in each row of a grid.
+fn rolling_rows<T: Clone + Debug + Hash + 'static>(
+    grid: &Grid<T>,
+    width: usize,
+) -> Vec<Vec<u64>> {
+    grid.rows()
+        .map(|row| {
+            let row = row.iter().map(hash).collect::<Vec<_>>();
+            rolling(&row, width, ROW_BASE)
+        })
+        .collect()
+}
+
+// Polynomial hashes of all windows of a given width (wrapping arithmetic).
+fn rolling(values: &[u64], width: usize, base: u64) -> Vec<u64> {
+    if width == 0 || width > values.len() {
+        return Vec::new();
+    }
+    let top = (1..width).fold(1u64, |acc, _| acc.wrapping_mul(base));
+    let mut ret = Vec::with_capacity(values.len() - width + 1);
+    let mut h = values[..width]
+        .iter()
+        .fold(0u64, |acc, v| acc.wrapping_mul(base).wrapping_add(*v));
+    ret.push(h);
+    for i in width..values.len() {
+        h = h
+            .wrapping_sub(values[i - width].wrapping_mul(top))
+            .wrapping_mul(base)
+            .wrapping_add(values[i]);
+        ret.push(h);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(
+        grid: &Grid<char>,
+        pattern: &Grid<char>,
+        wildcard: Option<&char>,
+    ) -> Vec<Cell> {
+        let (rows, cols) = grid.size();
+        let (prows, pcols) = pattern.size();
+        let mut ret = Vec::new();
+        for row in 0..=(rows - prows) {
+            for col in 0..=(cols - pcols) {
+                if grid.matches(pattern, wildcard, &(row, col)) {
+                    ret.push((row, col));
+                }
+            }
+        }
+        ret
+    }
+
+    fn random(rows: usize, cols: usize, seed: u64) -> Grid<char> {
+        let mut x = seed;
+        let lines = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| {
+                        x = x.wrapping_mul(6364136223846793005).wrapping_add(1);
+                        if (x >> 33).is_multiple_of(3) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Grid::raw(lines)
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid = Grid::raw(vec![
+            "ab.ab".to_owned(),
+            "cd.cd".to_owned(),
+            "abab.".to_owned(),
+            "cdcd.".to_owned(),
+        ]);
+        let pattern = Grid::raw(vec!["ab".to_owned(), "cd".to_owned()]);
+        assert_eq!(
+            grid.find_pattern(&pattern, None),
+            vec![(0, 0), (0, 3), (2, 0), (2, 2)]
+        );
+
+        let pattern = Grid::raw(vec!["a?".to_owned(), "?d".to_owned()]);
+        assert_eq!(
+            grid.find_pattern(&pattern, Some(&'?')),
+            vec![(0, 0), (0, 3), (2, 0), (2, 2)]
+        );
+
+        let pattern = Grid::raw(vec!["??".to_owned()]);
+        assert_eq!(grid.find_pattern(&pattern, Some(&'?')).len(), 16);
+
+        let large = Grid::raw(vec!["abcdef".to_owned()]);
+        assert!(grid.find_pattern(&large, None).is_empty());
+    }
+
+    #[test]
+    fn test_find_pattern_random() {
+        let grid = random(40, 50, 42);
+        for (idx, lines) in [
+            vec!["#."],
+            vec!["#.", ".#"],
+            vec!["##", "..", ".#"],
+            vec!["# #", " . ", "# #"],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let pattern =
+                Grid::raw(lines.iter().map(|s| s.to_string()).collect());
+            let wildcard = (idx == 3).then_some(&' ');
+            assert_eq!(
+                grid.find_pattern(&pattern, wildcard),
+                naive(&grid, &pattern, wildcard)
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_pattern_dihedral() {
+        let grid = Grid::raw(vec![
+            "#...".to_owned(),
+            "##..".to_owned(),
+            "...#".to_owned(),
+            "..##".to_owned(),
+        ]);
+        let pattern = Grid::raw(vec!["#?".to_owned(), "##".to_owned()]);
+        let found = grid.find_pattern_dihedral(&pattern, Some(&'?'));
+        let total = found.iter().map(|(_, cells)| cells.len()).sum::<usize>();
+        assert_eq!(found[0], (0, vec![(0, 0)]));
+        // every L shape is both a rotation and a reflection of the pattern
+        assert_eq!(total, 4);
+    }
+}
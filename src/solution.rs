@@ -0,0 +1,96 @@
+/// Puzzle solution: parsing of the input and both parts
+pub trait Solution {
+    /// Parsed puzzle input
+    type Input;
+
+    /// Parse input lines
+    fn parse(lines: Vec<String>) -> Self::Input;
+
+    /// Solve the first part
+    fn part1(input: &Self::Input) -> String;
+
+    /// Solve the second part (None if it is not solved)
+    fn part2(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Which parts of a solution to run
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+/// Type-erased solution: parses input lines and returns answers
+pub type Runner = fn(Vec<String>, Part) -> Vec<String>;
+
+/// Parse input lines and solve requested parts
+pub fn run<S: Solution>(lines: Vec<String>, part: Part) -> Vec<String> {
+    let input = S::parse(lines);
+    let mut ret = Vec::new();
+    if part != Part::Two {
+        ret.push(S::part1(&input));
+    }
+    if part != Part::One {
+        ret.extend(S::part2(&input));
+    }
+    ret
+}
+
+/// Solve both parts for input from stdin and print answers as they come
+pub fn main<S: Solution>() {
+    let input = S::parse(crate::lines());
+    println!("{}", S::part1(&input));
+    if let Some(answer) = S::part2(&input) {
+        println!("{answer}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(lines: Vec<String>) -> Self::Input {
+            lines.iter().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> String {
+            input.iter().sum::<i64>().to_string()
+        }
+    }
+
+    struct Product;
+
+    impl Solution for Product {
+        type Input = Vec<i64>;
+
+        fn parse(lines: Vec<String>) -> Self::Input {
+            Sum::parse(lines)
+        }
+
+        fn part1(input: &Self::Input) -> String {
+            Sum::part1(input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<String> {
+            Some(input.iter().product::<i64>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let lines = || vec!["2".to_owned(), "3".to_owned(), "4".to_owned()];
+        assert_eq!(run::<Sum>(lines(), Part::Both), vec!["9"]);
+        assert_eq!(run::<Sum>(lines(), Part::Two), Vec::<String>::new());
+        assert_eq!(run::<Product>(lines(), Part::Both), vec!["9", "24"]);
+        assert_eq!(run::<Product>(lines(), Part::One), vec!["9"]);
+        assert_eq!(run::<Product>(lines(), Part::Two), vec!["24"]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet as Set;
+use std::fmt::{Debug, Write};
+
+use crate::grid::{Cell, Grid};
+use crate::image::Rgb;
+
+/// Shape drawn on top of the grid cells
+#[derive(Clone, Debug)]
+pub enum Shape {
+    /// Line through the centers of given cells
+    Path { cells: Vec<Cell>, color: Rgb },
+    /// Semi-transparent cover over given cells
+    Region { cells: Set<Cell>, color: Rgb },
+}
+
+impl<T: Clone + Debug + 'static> Grid<T> {
+    /// Render the grid as SVG, each cell is a `scale`-sized square with an
+    /// optional fill color and text glyph, shapes are drawn on top in order
+    pub fn svg(
+        &self,
+        scale: usize,
+        f: impl Fn(&Cell, &T) -> (Option<Rgb>, Option<char>),
+        shapes: &[Shape],
+    ) -> String {
+        let (rows, cols) = self.size();
+        let (w, h) = (cols * scale, rows * scale);
+        let mut ret = String::new();
+        let _ = writeln!(
+            ret,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#
+        );
+
+        let mut glyphs = Vec::new();
+        for (row, col, val) in self.iter() {
+            let (fill, glyph) = f(&(row, col), val);
+            if let Some(color) = fill {
+                rect(&mut ret, scale, &(row, col), color, None);
+            }
+            if let Some(chr) = glyph {
+                glyphs.push(((row, col), chr));
+            }
+        }
+        for ((row, col), chr) in glyphs {
+            let (x, y) = center(scale, &(row, col));
+            let chr = match chr {
+                '&' => "&amp;".to_owned(),
+                '<' => "&lt;".to_owned(),
+                '>' => "&gt;".to_owned(),
+                c => c.to_string(),
+            };
+            let _ = writeln!(
+                ret,
+                r#"<text x="{x}" y="{y}" font-size="{}" font-family="monospace" text-anchor="middle" dominant-baseline="central">{chr}</text>"#,
+                scale * 3 / 4,
+            );
+        }
+
+        for shape in shapes {
+            match shape {
+                Shape::Path { cells, color } => {
+                    let points = cells
+                        .iter()
+                        .map(|cell| {
+                            let (x, y) = center(scale, cell);
+                            format!("{x},{y}")
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let _ = writeln!(
+                        ret,
+                        r#"<polyline points="{points}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+                        hex(color),
+                        (scale / 4).max(1),
+                    );
+                }
+                Shape::Region { cells, color } => {
+                    let mut cells = cells.iter().collect::<Vec<_>>();
+                    cells.sort();
+                    for cell in cells {
+                        rect(&mut ret, scale, cell, *color, Some(0.4));
+                    }
+                }
+            }
+        }
+
+        ret.push_str("</svg>\n");
+        ret
+    }
+}
+
+fn rect(
+    out: &mut String,
+    scale: usize,
+    cell: &Cell,
+    color: Rgb,
+    alpha: Option<f64>,
+) {
+    let (row, col) = *cell;
+    let (x, y) = (col * scale, row * scale);
+    let opacity = alpha
+        .map(|a| format!(r#" fill-opacity="{a}""#))
+        .unwrap_or_default();
+    let _ = writeln!(
+        out,
+        r#"<rect x="{x}" y="{y}" width="{scale}" height="{scale}" fill="{}"{opacity}/>"#,
+        hex(&color),
+    );
+}
+
+fn center(scale: usize, cell: &Cell) -> (f64, f64) {
+    let (row, col) = *cell;
+    let half = scale as f64 / 2.0;
+    ((col * scale) as f64 + half, (row * scale) as f64 + half)
+}
+
+fn hex(color: &Rgb) -> String {
+    let [r, g, b] = color;
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let grid = Grid::raw(vec!["#<".to_owned(), "..".to_owned()]);
+        let svg = grid.svg(
+            10,
+            |_, c| match c {
+                '#' => (Some([0, 0, 0]), None),
+                '.' => (None, None),
+                c => (None, Some(*c)),
+            },
+            &[
+                Shape::Path {
+                    cells: vec![(1, 0), (1, 1), (0, 1)],
+                    color: [255, 0, 0],
+                },
+                Shape::Region {
+                    cells: Set::from([(1, 1)]),
+                    color: [0, 255, 0],
+                },
+            ],
+        );
+        let lines = svg.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].contains(r#"width="20" height="20""#));
+        assert_eq!(
+            lines[1],
+            r##"<rect x="0" y="0" width="10" height="10" fill="#000000"/>"##
+        );
+        assert!(lines[2].starts_with(r#"<text x="15" y="5""#));
+        assert!(lines[2].ends_with(">&lt;</text>"));
+        assert!(lines[3].contains(r#"points="5,15 15,15 15,5""#));
+        assert!(lines[3].contains(r##"stroke="#ff0000""##));
+        assert!(lines[4].contains(r#"x="10" y="10""#));
+        assert!(lines[4].contains(r#"fill-opacity="0.4""#));
+        assert_eq!(lines[5], "</svg>");
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap as Map;
+use std::fmt::{Debug, Write as _};
+use std::io::{self, IsTerminal, Stdout, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::{Cell, Grid};
+use crate::image::Rgb;
+
+/// Foreground and background colors of a terminal cell
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Style {
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl Style {
+    /// Create a style with a foreground color
+    pub fn fg(color: Rgb) -> Self {
+        Self {
+            fg: Some(color),
+            bg: None,
+        }
+    }
+
+    /// Create a style with a background color
+    pub fn bg(color: Rgb) -> Self {
+        Self {
+            fg: None,
+            bg: Some(color),
+        }
+    }
+
+    /// Set background color of the style
+    pub fn on(self, color: Rgb) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    // Get ANSI escape sequence that switches to this style.
+    fn ansi(&self) -> String {
+        let mut ret = String::from("\x1b[0m");
+        if let Some([r, g, b]) = self.fg {
+            let _ = write!(ret, "\x1b[38;2;{r};{g};{b}m");
+        }
+        if let Some([r, g, b]) = self.bg {
+            let _ = write!(ret, "\x1b[48;2;{r};{g};{b}m");
+        }
+        ret
+    }
+}
+
+/// Set of cells drawn on top of a rendered grid
+#[derive(Clone, Debug, Default)]
+pub struct Overlay {
+    cells: Map<Cell, Option<char>>,
+    style: Style,
+}
+
+impl Overlay {
+    /// Highlight given cells with a style, keeping their glyphs
+    pub fn cells(cells: impl IntoIterator<Item = Cell>, style: Style) -> Self {
+        Self {
+            cells: cells.into_iter().map(|cell| (cell, None)).collect(),
+            style,
+        }
+    }
+
+    /// Draw a path with arrows (`^>v<`) pointing to the next cell
+    pub fn path(path: &[Cell], style: Style) -> Self {
+        let mut cells: Map<Cell, Option<char>> = Map::new();
+        for pair in path.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let arrow = match (b.0.cmp(&a.0), b.1.cmp(&a.1)) {
+                (std::cmp::Ordering::Less, _) => '^',
+                (std::cmp::Ordering::Greater, _) => 'v',
+                (_, std::cmp::Ordering::Less) => '<',
+                (_, std::cmp::Ordering::Greater) => '>',
+                _ => '*',
+            };
+            cells.insert(a, Some(arrow));
+        }
+        if let Some(last) = path.last() {
+            cells.entry(*last).or_insert(Some('*'));
+        }
+        Self { cells, style }
+    }
+}
+
+impl<T: Clone + Debug + 'static> Grid<T> {
+    /// Render the grid with ANSI colors, overlays are applied in order
+    /// (later ones take precedence)
+    pub fn render_ansi(
+        &self,
+        f: impl Fn(&Cell, &T) -> (char, Style),
+        overlays: &[Overlay],
+    ) -> String {
+        let mut ret = String::new();
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                ret.push('\n');
+            }
+            let mut last = Style::default();
+            for (col, val) in row.iter().enumerate() {
+                let cell = (idx, col);
+                let (mut chr, mut style) = f(&cell, val);
+                for overlay in overlays {
+                    if let Some(glyph) = overlay.cells.get(&cell) {
+                        chr = glyph.unwrap_or(chr);
+                        style = overlay.style;
+                    }
+                }
+                if style != last {
+                    ret.push_str(&style.ansi());
+                    last = style;
+                }
+                ret.push(chr);
+            }
+            if last != Style::default() {
+                ret.push_str("\x1b[0m");
+            }
+        }
+        ret
+    }
+}
+
+/// Redraws frames in place in the terminal at a fixed frame rate
+pub struct Animator<W: Write = Stdout> {
+    out: W,
+    delay: Duration,
+    enabled: bool,
+    lines: usize,
+}
+
+impl Animator<Stdout> {
+    /// Create a new animator on stdout (disabled if stdout is not a TTY)
+    pub fn new(fps: u32) -> Self {
+        let out = io::stdout();
+        let enabled = out.is_terminal();
+        Self::with_writer(out, fps, enabled)
+    }
+}
+
+impl<W: Write> Animator<W> {
+    /// Create a new animator writing to a given output
+    pub fn with_writer(out: W, fps: u32, enabled: bool) -> Self {
+        Self {
+            out,
+            delay: Duration::from_secs(1) / fps.max(1),
+            enabled,
+            lines: 0,
+        }
+    }
+
+    /// Check if animation is enabled (frames are dropped otherwise)
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Draw a frame over the previous one and wait for the frame delay
+    pub fn frame(&mut self, text: &str) -> io::Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.lines > 0 {
+            // move cursor to the beginning of the previous frame
+            write!(self.out, "\x1b[{}F", self.lines)?;
+        }
+        for line in text.lines() {
+            writeln!(self.out, "\x1b[2K{line}")?;
+        }
+        write!(self.out, "\x1b[J")?;
+        self.out.flush()?;
+        self.lines = text.lines().count();
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_ansi() {
+        let grid = Grid::raw(vec!["#..".to_owned(), "...".to_owned()]);
+        let plain = grid.render_ansi(|_, c| (*c, Style::default()), &[]);
+        assert_eq!(plain, "#..\n...");
+
+        let red = Style::fg([255, 0, 0]);
+        let wall = grid.render_ansi(
+            |_, c| (*c, if c == &'#' { red } else { Style::default() }),
+            &[],
+        );
+        assert_eq!(wall, "\x1b[0m\x1b[38;2;255;0;0m#\x1b[0m..\n...");
+
+        let blue = Style::bg([0, 0, 255]);
+        let path = Overlay::path(&[(1, 0), (1, 1), (0, 1)], blue);
+        let seen = Overlay::cells([(0, 2)], red.on([0, 0, 0]));
+        let out =
+            grid.render_ansi(|_, c| (*c, Style::default()), &[path, seen]);
+        let b = blue.ansi();
+        let r = red.on([0, 0, 0]).ansi();
+        let x = "\x1b[0m";
+        assert_eq!(out, format!("#{b}*{r}.{x}\n{b}>^{x}."));
+    }
+
+    #[test]
+    fn test_animator() {
+        let mut out = Vec::new();
+        let mut animator = Animator::with_writer(&mut out, 1000, false);
+        animator.frame("a\nb").unwrap();
+        assert!(!animator.enabled());
+        assert!(out.is_empty());
+
+        let mut animator = Animator::with_writer(&mut out, 1000, true);
+        animator.frame("a\nb").unwrap();
+        animator.frame("c").unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2Ka\n\x1b[2Kb\n\x1b[J\x1b[2F\x1b[2Kc\n\x1b[J"
+        );
+    }
+}
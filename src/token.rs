@@ -0,0 +1,155 @@
+use std::fmt::Debug;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::grid::{Cell, Grid};
+
+/// Horizontal run of token characters within a single grid row
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Span {
+    pub row: usize,
+    pub cols: Range<usize>,
+    pub text: String,
+}
+
+impl Span {
+    /// Get number of cells covered by the span
+    pub fn len(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// Check if the span covers no cells
+    pub fn is_empty(&self) -> bool {
+        self.cols.is_empty()
+    }
+
+    /// Parse span text into a value
+    pub fn parse<F: FromStr>(&self) -> Option<F> {
+        self.text.parse().ok()
+    }
+
+    /// Check if a cell is covered by the span
+    pub fn contains(&self, pos: &Cell) -> bool {
+        pos.0 == self.row && self.cols.contains(&pos.1)
+    }
+
+    /// Check if a cell is adjacent to the span (diagonals included) but
+    /// not covered by it
+    pub fn touches(&self, pos: &Cell) -> bool {
+        let (row, col) = *pos;
+        row.abs_diff(self.row) <= 1
+            && col + 1 >= self.cols.start
+            && col <= self.cols.end
+            && !self.contains(pos)
+    }
+
+    /// Get cells covered by the span
+    pub fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        self.cols.clone().map(|col| (self.row, col))
+    }
+
+    /// Get cells surrounding the span within grid bounds, by rows
+    pub fn neighbors<T: Clone + Debug + 'static>(
+        &self,
+        grid: &Grid<T>,
+    ) -> Vec<Cell> {
+        let (rows, cols) = grid.size();
+        let rs = self.row.saturating_sub(1)..(self.row + 2).min(rows);
+        let cs =
+            self.cols.start.saturating_sub(1)..(self.cols.end + 1).min(cols);
+        rs.flat_map(|row| cs.clone().map(move |col| (row, col)))
+            .filter(|pos| !self.contains(pos))
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Extract maximal horizontal runs of characters satisfying a predicate
+    pub fn tokens(&self, is_token_char: impl Fn(char) -> bool) -> Vec<Span> {
+        let mut ret = Vec::new();
+        for (row, line) in self.rows().enumerate() {
+            let mut col = 0;
+            while col < line.len() {
+                if !is_token_char(line[col]) {
+                    col += 1;
+                    continue;
+                }
+                let lo = col;
+                while col < line.len() && is_token_char(line[col]) {
+                    col += 1;
+                }
+                ret.push(Span {
+                    row,
+                    cols: lo..col,
+                    text: line[lo..col].iter().collect(),
+                });
+            }
+        }
+        ret
+    }
+}
+
+/// Get spans adjacent to a given cell
+pub fn touching<'a>(
+    spans: &'a [Span],
+    pos: &'a Cell,
+) -> impl Iterator<Item = &'a Span> + 'a {
+    spans.iter().filter(move |span| span.touches(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let grid = Grid::raw(vec![
+            "467..114..".to_owned(),
+            "...*......".to_owned(),
+            "..35..633.".to_owned(),
+        ]);
+        let spans = grid.tokens(|c| c.is_ascii_digit());
+        assert_eq!(
+            spans
+                .iter()
+                .map(|s| (s.row, s.cols.clone(), s.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, 0..3, "467"),
+                (0, 5..8, "114"),
+                (2, 2..4, "35"),
+                (2, 6..9, "633"),
+            ]
+        );
+        assert_eq!(spans[3].parse::<usize>(), Some(633));
+        assert_eq!(spans[2].len(), 2);
+        assert_eq!(spans[2].cells().collect::<Vec<_>>(), vec![(2, 2), (2, 3)]);
+
+        let gear = (1, 3);
+        assert_eq!(
+            touching(&spans, &gear)
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["467", "35"]
+        );
+        assert!(spans[0].neighbors(&grid).contains(&gear));
+        assert!(!spans[1].neighbors(&grid).contains(&gear));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::raw(vec!["ab.".to_owned(), "...".to_owned()]);
+        let spans = grid.tokens(|c| c.is_alphabetic());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(
+            spans[0].neighbors(&grid),
+            vec![(0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        for (row, col, _) in grid.iter() {
+            assert_eq!(
+                spans[0].touches(&(row, col)),
+                spans[0].neighbors(&grid).contains(&(row, col))
+            );
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt::Debug;
+
+use crate::grid::{Cell, Grid};
+
+/// Borrowed rectangular region of a grid (positions are relative to the
+/// top-left corner of the region)
+#[derive(Debug)]
+pub struct GridView<'a, T: Clone + Debug + 'static> {
+    grid: &'a Grid<T>,
+    origin: Cell,
+    size: (usize, usize),
+}
+
+// Derived impls would require `T: Copy`, while only the reference is copied.
+impl<'a, T: Clone + Debug + 'static> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: Clone + Debug + 'static> Copy for GridView<'a, T> {}
+
+/// Mutably borrowed rectangular region of a grid
+#[derive(Debug)]
+pub struct GridViewMut<'a, T: Clone + Debug + 'static> {
+    grid: &'a mut Grid<T>,
+    origin: Cell,
+    size: (usize, usize),
+}
+
+impl<T: Clone + Debug + 'static> Grid<T> {
+    /// Get view of a region with given top-left corner and size (rows, cols)
+    pub fn view(
+        &self,
+        origin: Cell,
+        size: (usize, usize),
+    ) -> Option<GridView<'_, T>> {
+        fits(self.size(), origin, size).then_some(GridView {
+            grid: self,
+            origin,
+            size,
+        })
+    }
+
+    /// Get mutable view of a region with given top-left corner and size
+    pub fn view_mut(
+        &mut self,
+        origin: Cell,
+        size: (usize, usize),
+    ) -> Option<GridViewMut<'_, T>> {
+        fits(self.size(), origin, size).then_some(GridViewMut {
+            grid: self,
+            origin,
+            size,
+        })
+    }
+
+    /// Iterate over all (overlapping) regions of a given size, by rows
+    pub fn windows(
+        &self,
+        rows: usize,
+        cols: usize,
+    ) -> impl Iterator<Item = GridView<'_, T>> {
+        let (total_rows, total_cols) = self.size();
+        let (last_row, last_col) = if rows == 0 || cols == 0 {
+            (0, 0)
+        } else {
+            (
+                (total_rows + 1).saturating_sub(rows),
+                (total_cols + 1).saturating_sub(cols),
+            )
+        };
+        (0..last_row).flat_map(move |row| {
+            (0..last_col).map(move |col| GridView {
+                grid: self,
+                origin: (row, col),
+                size: (rows, cols),
+            })
+        })
+    }
+
+    /// Iterate over non-overlapping regions of a given size, by rows
+    /// (regions at the bottom and right edges may be smaller)
+    pub fn chunks(
+        &self,
+        rows: usize,
+        cols: usize,
+    ) -> impl Iterator<Item = GridView<'_, T>> {
+        assert!(rows > 0 && cols > 0, "chunk size must be non-zero");
+        let (total_rows, total_cols) = self.size();
+        (0..total_rows).step_by(rows).flat_map(move |row| {
+            (0..total_cols).step_by(cols).map(move |col| GridView {
+                grid: self,
+                origin: (row, col),
+                size: (rows.min(total_rows - row), cols.min(total_cols - col)),
+            })
+        })
+    }
+}
+
+impl<'a, T: Clone + Debug + 'static> GridView<'a, T> {
+    /// Get view size (rows, cols)
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Get position of the top-left corner in the underlying grid
+    pub fn origin(&self) -> Cell {
+        self.origin
+    }
+
+    /// Convert position in the view to position in the underlying grid
+    pub fn global(&self, pos: &Cell) -> Cell {
+        (self.origin.0 + pos.0, self.origin.1 + pos.1)
+    }
+
+    /// Get grid item at a given position
+    pub fn get(&self, pos: &Cell) -> Option<&'a T> {
+        let (rows, cols) = self.size;
+        if pos.0 < rows && pos.1 < cols {
+            self.grid.get(&self.global(pos))
+        } else {
+            None
+        }
+    }
+
+    /// Get iterator over the rows of the view
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (row, col) = self.origin;
+        let (rows, cols) = self.size;
+        self.grid
+            .rows()
+            .skip(row)
+            .take(rows)
+            .map(move |r| &r[col..col + cols])
+    }
+
+    /// Iterate over view cells by rows, each row by column
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, val)| (row, col, val))
+        })
+    }
+
+    /// Find positions of view cells that match a predicate
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Vec<Cell> {
+        self.iter()
+            .filter(|(_, _, val)| f(val))
+            .map(|(row, col, _)| (row, col))
+            .collect()
+    }
+
+    /// Get string representation of the view
+    pub fn dump(&self, f: impl Fn(&Cell, T) -> char) -> String {
+        self.rows()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, c)| f(&(i, j), c.clone()))
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Get view of a region within this view
+    pub fn view(&self, origin: Cell, size: (usize, usize)) -> Option<Self> {
+        fits(self.size, origin, size).then_some(GridView {
+            grid: self.grid,
+            origin: self.global(&origin),
+            size,
+        })
+    }
+
+    /// Copy the view contents into a new grid
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_rows(self.size, self.rows().map(|r| r.to_vec()).collect())
+    }
+}
+
+impl<'a, T: Clone + Debug + 'static> GridViewMut<'a, T> {
+    /// Get view size (rows, cols)
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Get read-only view of the same region
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            origin: self.origin,
+            size: self.size,
+        }
+    }
+
+    /// Get grid item at a given position
+    pub fn get(&self, pos: &Cell) -> Option<&T> {
+        self.as_view().get(pos)
+    }
+
+    /// Get mutable reference to a grid item at a given position
+    pub fn get_mut(&mut self, pos: &Cell) -> Option<&mut T> {
+        let (rows, cols) = self.size;
+        if pos.0 < rows && pos.1 < cols {
+            let pos = (self.origin.0 + pos.0, self.origin.1 + pos.1);
+            self.grid.get_mut(&pos)
+        } else {
+            None
+        }
+    }
+
+    /// Set grid item at a given position
+    pub fn set(&mut self, pos: &Cell, val: T) {
+        *self.get_mut(pos).unwrap() = val;
+    }
+
+    /// Set all items of the view to a given value
+    pub fn fill(&mut self, val: T) {
+        let (rows, cols) = self.size;
+        for row in 0..rows {
+            for col in 0..cols {
+                self.set(&(row, col), val.clone());
+            }
+        }
+    }
+}
+
+// Check if a region fits into bounds of a given size.
+fn fits(bounds: (usize, usize), origin: Cell, size: (usize, usize)) -> bool {
+    origin.0 + size.0 <= bounds.0 && origin.1 + size.1 <= bounds.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::raw(vec![
+            "1234".to_owned(),
+            "5678".to_owned(),
+            "9ABC".to_owned(),
+        ])
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = grid();
+        let view = grid.view((1, 1), (2, 3)).unwrap();
+        assert_eq!(view.size(), (2, 3));
+        assert_eq!(view.get(&(0, 0)), Some(&'6'));
+        assert_eq!(view.get(&(1, 2)), Some(&'C'));
+        assert_eq!(view.get(&(2, 0)), None);
+        assert_eq!(view.dump(|_, c| c), "678\nABC");
+        assert_eq!(
+            view.find(|c| c.is_ascii_alphabetic()),
+            vec![(1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(view.global(&(1, 0)), (2, 1));
+        assert_eq!(view.view((1, 1), (1, 2)).unwrap().dump(|_, c| c), "BC");
+        assert_eq!(view.to_grid().dump(|_, c| c), "678\nABC");
+        assert!(grid.view((1, 1), (3, 1)).is_none());
+
+        let (rows, cols) = grid.size();
+        let empty = grid.view((rows, 0), (0, cols)).unwrap().to_grid();
+        assert_eq!(empty.size(), (0, cols));
+        let empty = grid.view((0, cols), (rows, 0)).unwrap().to_grid();
+        assert_eq!(empty.size(), (rows, 0));
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut grid = grid();
+        let mut view = grid.view_mut((0, 2), (3, 2)).unwrap();
+        view.fill('.');
+        view.set(&(1, 0), '#');
+        assert_eq!(view.get(&(1, 0)), Some(&'#'));
+        assert!(view.get_mut(&(0, 2)).is_none());
+        assert_eq!(grid.dump(|_, c| c), "12..\n56#.\n9A..");
+    }
+
+    #[test]
+    fn test_windows_chunks() {
+        let grid = grid();
+        let windows = grid
+            .windows(2, 2)
+            .map(|v| v.dump(|_, c| c))
+            .collect::<Vec<_>>();
+        assert_eq!(windows.len(), 6);
+        assert_eq!(windows[0], "12\n56");
+        assert_eq!(windows[5], "78\nBC");
+        assert_eq!(grid.windows(4, 1).count(), 0);
+
+        let chunks = grid
+            .chunks(2, 3)
+            .map(|v| (v.origin(), v.size()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chunks,
+            vec![
+                ((0, 0), (2, 3)),
+                ((0, 3), (2, 1)),
+                ((2, 0), (1, 3)),
+                ((2, 3), (1, 1))
+            ]
+        );
+    }
+}